@@ -0,0 +1,189 @@
+use alloc::{string::String, vec::Vec};
+use core::{fmt, ops::Range};
+
+use crate::ShortName;
+
+/// A [`ShortName`] which has already been shortened, for displaying, measuring or
+/// comparing the same name many times without scanning it again.
+///
+/// Rather than a copy of the shortened name, this keeps the ranges of the original
+/// name which make it up, so that it takes little memory for long names.
+///
+/// Returned by [`ShortName::analyzed`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::ShortName;
+/// let name = ShortName("bevy_asset::assets::Assets<bevy_image::Image>").analyzed();
+/// assert_eq!(name.to_string(), "Assets<Image>");
+/// assert_eq!(name.len(), 13);
+/// assert_eq!(name, "Assets<Image>");
+/// ```
+#[derive(Clone, Debug)]
+pub struct AnalyzedName<'a> {
+    original: &'a str,
+    parts: Vec<Part>,
+    /// Text which is not found in the original name, as when a default
+    /// configuration changes how the name is written.
+    owned: String,
+    len: usize,
+}
+
+/// Where a run of the shortened name comes from.
+#[derive(Clone, Debug)]
+enum Part {
+    Original(Range<usize>),
+    Owned(Range<usize>),
+}
+
+impl<'a> AnalyzedName<'a> {
+    pub(crate) fn new(name: ShortName<'a>) -> Self {
+        let mut analyzed = Self {
+            original: name.0,
+            parts: Vec::new(),
+            owned: String::new(),
+            len: 0,
+        };
+        // Recording the parts never fails.
+        let _ = name.write_to(&mut Record(&mut analyzed));
+        analyzed
+    }
+
+    /// Gets the original name before shortening.
+    pub const fn original(&self) -> &'a str {
+        self.original
+    }
+
+    /// Gets the length in bytes of the shortened name.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the shortened name is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets an iterator over runs of the shortened name, which together make it up.
+    pub fn chunks(&self) -> impl Iterator<Item = &str> + '_ {
+        self.parts.iter().map(|part| match part {
+            Part::Original(range) => &self.original[range.clone()],
+            Part::Owned(range) => &self.owned[range.clone()],
+        })
+    }
+}
+
+/// Records the runs of a shortened name written through it.
+struct Record<'r, 'a>(&'r mut AnalyzedName<'a>);
+
+impl<'r, 'a> fmt::Write for Record<'r, 'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let analyzed = &mut *self.0;
+        if s.is_empty() {
+            return Ok(());
+        }
+        let offset = (s.as_ptr() as usize)
+            .checked_sub(analyzed.original.as_ptr() as usize)
+            .filter(|offset| offset + s.len() <= analyzed.original.len());
+        let part = match offset {
+            Some(offset) => Part::Original(offset..offset + s.len()),
+            None => {
+                let start = analyzed.owned.len();
+                analyzed.owned.push_str(s);
+                Part::Owned(start..analyzed.owned.len())
+            }
+        };
+        analyzed.len += s.len();
+
+        // Runs which follow on from each other are merged.
+        match (analyzed.parts.last_mut(), part) {
+            (Some(Part::Original(last)), Part::Original(next)) if last.end == next.start => {
+                last.end = next.end;
+            }
+            (Some(Part::Owned(last)), Part::Owned(next)) if last.end == next.start => {
+                last.end = next.end;
+            }
+            (_, part) => analyzed.parts.push(part),
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Display for AnalyzedName<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::display::pad(f, |w| {
+            self.chunks().try_for_each(|chunk| w.write_str(chunk))
+        })
+    }
+}
+
+impl<'a> PartialEq<str> for AnalyzedName<'a> {
+    fn eq(&self, other: &str) -> bool {
+        if self.len != other.len() {
+            return false;
+        }
+        let mut rest = other.as_bytes();
+        self.chunks().all(|chunk| {
+            let (start, tail) = rest.split_at(chunk.len());
+            rest = tail;
+            start == chunk.as_bytes()
+        })
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for AnalyzedName<'a> {
+    fn eq(&self, other: &&'b str) -> bool {
+        *self == **other
+    }
+}
+
+impl<'a, 'b> PartialEq<AnalyzedName<'b>> for AnalyzedName<'a> {
+    fn eq(&self, other: &AnalyzedName<'b>) -> bool {
+        self.len == other.len
+            && self
+                .chunks()
+                .flat_map(str::bytes)
+                .eq(other.chunks().flat_map(str::bytes))
+    }
+}
+
+impl<'a> Eq for AnalyzedName<'a> {}
+
+#[cfg(test)]
+mod analyzed_tests {
+    use crate::ShortName;
+    use alloc::{format, string::ToString, vec::Vec};
+
+    #[test]
+    fn borrows_from_the_original() {
+        let name = ShortName("bevy_render::camera::extract_cameras<bevy_render::camera::Camera3d>")
+            .analyzed();
+        assert_eq!(name.to_string(), "extract_cameras<Camera3d>");
+        assert_eq!(name.len(), 25);
+        assert!(!name.is_empty());
+        assert!(name.owned.is_empty());
+
+        let chunks: Vec<_> = name.chunks().collect();
+        assert_eq!(chunks, ["extract_cameras<", "Camera3d>"]);
+    }
+
+    #[test]
+    fn compares_with_strings() {
+        let name = ShortName("a::B<c::D>").analyzed();
+        assert_eq!(name, "B<D>");
+        assert_ne!(name, "B<E>");
+        assert_ne!(name, "B<D>>");
+        assert_eq!(name, ShortName("e::B<f::D>").analyzed());
+        assert_ne!(name, ShortName("e::B<f::E>").analyzed());
+        assert!(ShortName("").analyzed().is_empty());
+        assert_eq!(ShortName("").analyzed(), "");
+    }
+
+    #[test]
+    fn padding() {
+        let name = ShortName("a::B<c::D>").analyzed();
+        assert_eq!(format!("[{name:>6}]"), "[  B<D>]");
+        assert_eq!(format!("[{name:.2}]"), "[B<]");
+    }
+}
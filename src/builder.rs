@@ -0,0 +1,221 @@
+use alloc::{boxed::Box, vec};
+use core::fmt;
+
+use crate::{
+    parse::{GenericArg, GenericArgs, PathSegment, Shortened, TypeExpr, TypePath},
+    Marker,
+};
+
+/// Assembles a type name from its parts, rendering it in the same way as
+/// [`core::any::type_name`].
+///
+/// This suits tools which generate names for types which do not exist yet, such
+/// as code generators, and need them to match the names of types which do. The
+/// builder displays the full name, while [`shortened`](Self::shortened) displays it
+/// as [`ShortName`](crate::ShortName) would.
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::TypeNameBuilder;
+/// let name = TypeNameBuilder::path("bevy_asset::Handle")
+///     .generic(TypeNameBuilder::path("bevy_image::Image"))
+///     .array("4")
+///     .reference();
+/// assert_eq!(name.to_string(), "&[bevy_asset::Handle<bevy_image::Image>; 4]");
+/// assert_eq!(name.shortened().to_string(), "&[Handle<Image>; 4]");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TypeNameBuilder<'a> {
+    expr: TypeExpr<'a>,
+}
+
+impl<'a> TypeNameBuilder<'a> {
+    /// Starts a name from a `::`-separated path, such as `alloc::vec::Vec`.
+    pub fn path(path: &'a str) -> Self {
+        let segments = path
+            .split("::")
+            .map(|ident| PathSegment {
+                ident,
+                marker: Marker::parse(ident),
+                args: None,
+            })
+            .collect();
+        Self {
+            expr: TypeExpr::Path(TypePath {
+                qself: None,
+                segments,
+            }),
+        }
+    }
+
+    /// Starts a name from a tuple of `elements`, which is `()` if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::TypeNameBuilder;
+    /// let name = TypeNameBuilder::tuple([TypeNameBuilder::path("u32")]);
+    /// assert_eq!(name.to_string(), "(u32,)");
+    /// ```
+    pub fn tuple(elements: impl IntoIterator<Item = TypeNameBuilder<'a>>) -> Self {
+        Self {
+            expr: TypeExpr::Tuple(elements.into_iter().map(|element| element.expr).collect()),
+        }
+    }
+
+    /// Adds a type to the generic arguments of the last segment of the path.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name is not a path, such as after it is wrapped in an array.
+    pub fn generic(self, ty: TypeNameBuilder<'a>) -> Self {
+        self.push_arg(GenericArg::Type(ty.expr))
+    }
+
+    /// Adds a lifetime, such as `'static`, to the generic arguments of the last
+    /// segment of the path.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name is not a path, such as after it is wrapped in an array.
+    pub fn generic_lifetime(self, lifetime: &'a str) -> Self {
+        self.push_arg(GenericArg::Lifetime(lifetime))
+    }
+
+    /// Adds a const, such as `16` or `true`, to the generic arguments of the last
+    /// segment of the path.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name is not a path, such as after it is wrapped in an array.
+    pub fn generic_const(self, value: &'a str) -> Self {
+        self.push_arg(GenericArg::Const(value))
+    }
+
+    fn push_arg(mut self, arg: GenericArg<'a>) -> Self {
+        let TypeExpr::Path(path) = &mut self.expr else {
+            panic!("generic arguments can only be added to a path");
+        };
+        let segment = path.segments.last_mut().expect("paths have a segment");
+        match &mut segment.args {
+            Some(GenericArgs::AngleBracketed(args)) => args.push(arg),
+            args => *args = Some(GenericArgs::AngleBracketed(vec![arg])),
+        }
+        self
+    }
+
+    /// Wraps the name in an array of `len` elements, such as `[T; 4]`.
+    pub fn array(self, len: &'a str) -> Self {
+        self.wrap(|element| TypeExpr::Array { element, len })
+    }
+
+    /// Wraps the name in a slice, such as `[T]`.
+    pub fn slice(self) -> Self {
+        self.wrap(TypeExpr::Slice)
+    }
+
+    /// Wraps the name in a shared reference, such as `&T`.
+    pub fn reference(self) -> Self {
+        self.wrap(|referent| TypeExpr::Reference {
+            lifetime: None,
+            mutable: false,
+            referent,
+        })
+    }
+
+    /// Wraps the name in a mutable reference, such as `&mut T`.
+    pub fn reference_mut(self) -> Self {
+        self.wrap(|referent| TypeExpr::Reference {
+            lifetime: None,
+            mutable: true,
+            referent,
+        })
+    }
+
+    fn wrap(self, wrapper: impl FnOnce(Box<TypeExpr<'a>>) -> TypeExpr<'a>) -> Self {
+        Self {
+            expr: wrapper(Box::new(self.expr)),
+        }
+    }
+
+    /// Gets a view of the name which displays with module paths removed,
+    /// in the same way as [`ShortName`](crate::ShortName).
+    pub fn shortened(&self) -> Shortened<'_, 'a> {
+        self.expr.shortened()
+    }
+
+    /// Gets the assembled name as a parsed [`TypeExpr`], for inspecting or changing
+    /// it further.
+    pub fn build(self) -> TypeExpr<'a> {
+        self.expr
+    }
+}
+
+impl<'a> From<TypeNameBuilder<'a>> for TypeExpr<'a> {
+    fn from(builder: TypeNameBuilder<'a>) -> Self {
+        builder.build()
+    }
+}
+
+impl<'a> fmt::Display for TypeNameBuilder<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.expr.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod builder_tests {
+    use super::TypeNameBuilder;
+    use crate::{parse::parse, ShortName};
+    use alloc::{string::ToString, vec::Vec};
+
+    #[test]
+    fn matches_type_name() {
+        let built = TypeNameBuilder::tuple([
+            TypeNameBuilder::path("alloc::vec::Vec").generic(TypeNameBuilder::path("u8")),
+            TypeNameBuilder::path("core::option::Option")
+                .generic(TypeNameBuilder::path("u32"))
+                .array("2"),
+            TypeNameBuilder::path("u8").slice().reference_mut(),
+            TypeNameBuilder::tuple([TypeNameBuilder::path("bool")]),
+        ]);
+        assert_eq!(
+            built.to_string(),
+            core::any::type_name::<(Vec<u8>, [Option<u32>; 2], &mut [u8], (bool,))>()
+        );
+
+        let built = TypeNameBuilder::path("a::Borrowed")
+            .generic_lifetime("'static")
+            .generic(TypeNameBuilder::path("str"))
+            .generic_const("3");
+        assert_eq!(built.to_string(), "a::Borrowed<'static, str, 3>");
+    }
+
+    #[test]
+    fn renders_both_forms() {
+        let name = TypeNameBuilder::path("bevy_ecs::system::Query")
+            .generic(TypeNameBuilder::path("bevy_transform::Transform").reference())
+            .generic(TypeNameBuilder::tuple([]));
+        assert_eq!(
+            name.to_string(),
+            "bevy_ecs::system::Query<&bevy_transform::Transform, ()>"
+        );
+        assert_eq!(name.shortened().to_string(), "Query<&Transform, ()>");
+        assert_eq!(parse(&name.to_string()).unwrap(), name.build());
+
+        let name = TypeNameBuilder::path("a::b::C").generic(TypeNameBuilder::path("d::E"));
+        assert_eq!(
+            name.shortened().to_string(),
+            ShortName(&name.to_string()).to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic = "generic arguments can only be added to a path"]
+    fn generics_need_a_path() {
+        let _ = TypeNameBuilder::path("u8")
+            .slice()
+            .generic(TypeNameBuilder::path("u8"));
+    }
+}
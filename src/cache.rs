@@ -0,0 +1,121 @@
+use alloc::string::String;
+use core::{cell::OnceCell, fmt};
+
+use crate::ShortName;
+
+/// A [`ShortName`] which is shortened the first time it is displayed or compared,
+/// and then keeps the shortened name for later.
+///
+/// This sits between [`ShortName`], which shortens the name every time it is
+/// used, and shortening the name into a [`String`] up front, which costs an
+/// allocation even for names that are never shown.
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::{ShortName, ShortNameCache};
+/// let name = ShortNameCache::new(ShortName("bevy_asset::assets::Assets<bevy_image::Image>"));
+/// assert_eq!(name.original(), "bevy_asset::assets::Assets<bevy_image::Image>");
+/// assert_eq!(name, "Assets<Image>");
+/// assert_eq!(name.to_string(), "Assets<Image>");
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShortNameCache<'a> {
+    name: ShortName<'a>,
+    short: OnceCell<String>,
+}
+
+impl<'a> ShortNameCache<'a> {
+    /// Creates a cache for `name`, without shortening it yet.
+    pub const fn new(name: ShortName<'a>) -> Self {
+        Self {
+            name,
+            short: OnceCell::new(),
+        }
+    }
+
+    /// Gets the original name before shortening.
+    pub const fn original(&self) -> &'a str {
+        self.name.0
+    }
+
+    /// Gets the [`ShortName`] this caches.
+    pub const fn name(&self) -> ShortName<'a> {
+        self.name
+    }
+
+    /// Gets the shortened name, shortening it if this is the first time.
+    pub fn get(&self) -> &str {
+        self.short.get_or_init(|| self.name.into())
+    }
+
+    /// Gets the shortened name as a [`String`], reusing it if it was shortened
+    /// already.
+    pub fn into_string(self) -> String {
+        match self.short.into_inner() {
+            Some(short) => short,
+            None => self.name.into(),
+        }
+    }
+}
+
+impl<'a> From<ShortName<'a>> for ShortNameCache<'a> {
+    fn from(name: ShortName<'a>) -> Self {
+        Self::new(name)
+    }
+}
+
+impl<'a> fmt::Display for ShortNameCache<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::display::pad(f, |w| w.write_str(self.get()))
+    }
+}
+
+impl<'a> PartialEq<str> for ShortNameCache<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.get() == other
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for ShortNameCache<'a> {
+    fn eq(&self, other: &&'b str) -> bool {
+        self.get() == *other
+    }
+}
+
+impl<'a, 'b> PartialEq<ShortNameCache<'b>> for ShortNameCache<'a> {
+    fn eq(&self, other: &ShortNameCache<'b>) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<'a> Eq for ShortNameCache<'a> {}
+
+#[cfg(test)]
+mod cache_tests {
+    use super::ShortNameCache;
+    use crate::ShortName;
+    use alloc::format;
+
+    #[test]
+    fn shortens_once() {
+        let name = ShortNameCache::new(ShortName("a::B<c::D>"));
+        assert!(name.short.get().is_none());
+        assert_eq!(name.get(), "B<D>");
+        let short = name.get().as_ptr();
+        assert_eq!(format!("[{name:>6}]"), "[  B<D>]");
+        assert_eq!(name.get().as_ptr(), short);
+        assert_eq!(name.original(), "a::B<c::D>");
+        assert_eq!(name.name().original(), "a::B<c::D>");
+    }
+
+    #[test]
+    fn comparisons() {
+        let name = ShortNameCache::from(ShortName("a::B<c::D>"));
+        assert_eq!(name, "B<D>");
+        assert_ne!(name, "a::B<c::D>");
+        assert_eq!(name, ShortNameCache::new(ShortName("e::B<f::D>")));
+        assert_eq!(name.into_string(), "B<D>");
+        assert_eq!(ShortNameCache::new(ShortName("a::B")).into_string(), "B");
+    }
+}
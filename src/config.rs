@@ -0,0 +1,1322 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::parse::PathSegment;
+
+/// The configuration set by [`set_default_config`], if any.
+#[cfg(feature = "std")]
+static DEFAULT_CONFIG: std::sync::RwLock<Option<alloc::sync::Arc<ShortNameConfig>>> =
+    std::sync::RwLock::new(None);
+
+/// Whether [`DEFAULT_CONFIG`] may hold a configuration, so that names can be
+/// displayed without taking the lock until one is set.
+#[cfg(feature = "std")]
+static HAS_DEFAULT_CONFIG: core::sync::atomic::AtomicBool =
+    core::sync::atomic::AtomicBool::new(false);
+
+/// Sets the configuration which the [`Display`](core::fmt::Display) and
+/// [`Debug`](core::fmt::Debug) implementations of [`ShortName`](crate::ShortName)
+/// follow, for the whole process.
+///
+/// Until this is called, names are shortened as usual. Names displayed with
+/// [`ShortName::with_config`](crate::ShortName::with_config) still follow the
+/// configuration given there. The configuration can be removed again with
+/// [`reset_default_config`].
+///
+/// Names being displayed when this is called keep the configuration they started
+/// with, so it is safe to call while writing a name, such as from the callback of
+/// [`ShortName::display_with`](crate::ShortName::display_with).
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::{ShortName, ShortNameConfig};
+/// let name = ShortName("bevy_render::camera::Camera3d");
+/// assert_eq!(name.to_string(), "Camera3d");
+///
+/// disqualified::set_default_config(ShortNameConfig::new().keep_crate(true));
+/// assert_eq!(name.to_string(), "bevy_render::Camera3d");
+///
+/// let config = ShortNameConfig::new();
+/// assert_eq!(name.with_config(&config).to_string(), "Camera3d");
+///
+/// // Names also compare as they are displayed.
+/// assert_eq!(name, "bevy_render::Camera3d");
+/// assert_ne!(name, ShortName("bevy_pbr::Camera3d"));
+/// assert!(name.starts_with("bevy_render::") && name.contains("::Cam"));
+/// assert!(name.chars().eq("bevy_render::Camera3d".chars()));
+///
+/// // As do paths in `Debug` output.
+/// let event = disqualified::ShortDebug(Some("bevy_render::camera::Camera3d"));
+/// assert_eq!(event.to_string(), r#"Some("bevy_render::Camera3d")"#);
+///
+/// let logged = name.display_with(|piece, f| {
+///     disqualified::reset_default_config();
+///     f.write_str(piece.text)
+/// });
+/// assert_eq!(logged.to_string(), "bevy_render::Camera3d");
+/// assert_eq!(name.to_string(), "Camera3d");
+/// ```
+#[cfg(feature = "std")]
+pub fn set_default_config(config: ShortNameConfig) {
+    replace_default_config(Some(alloc::sync::Arc::new(config)));
+}
+
+/// Removes the configuration set by [`set_default_config`], so that names are
+/// shortened as usual again.
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::{ShortName, ShortNameConfig};
+/// let name = ShortName("bevy_render::camera::Camera3d");
+/// disqualified::set_default_config(ShortNameConfig::new().keep_crate(true));
+/// assert_eq!(name.to_string(), "bevy_render::Camera3d");
+///
+/// disqualified::reset_default_config();
+/// assert_eq!(name.to_string(), "Camera3d");
+/// ```
+#[cfg(feature = "std")]
+pub fn reset_default_config() {
+    replace_default_config(None);
+}
+
+#[cfg(feature = "std")]
+fn replace_default_config(config: Option<alloc::sync::Arc<ShortNameConfig>>) {
+    use core::sync::atomic::Ordering;
+
+    let mut default = DEFAULT_CONFIG
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    HAS_DEFAULT_CONFIG.store(config.is_some(), Ordering::Release);
+    *default = config;
+}
+
+/// The configuration set by [`set_default_config`], if any.
+///
+/// The lock is released before this returns, so that rendering with the
+/// configuration never holds it.
+#[cfg(feature = "std")]
+pub(crate) fn default_config() -> Option<alloc::sync::Arc<ShortNameConfig>> {
+    use core::sync::atomic::Ordering;
+
+    if !HAS_DEFAULT_CONFIG.load(Ordering::Acquire) {
+        return None;
+    }
+    DEFAULT_CONFIG
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+
+/// Options controlling how [`ShortName::with_config`](crate::ShortName::with_config)
+/// renders a type name.
+///
+/// The default configuration shortens names like the [`Display`](core::fmt::Display)
+/// implementation of [`ShortName`](crate::ShortName) does. Options are changed with
+/// builder methods, each of which takes and returns the configuration.
+///
+/// With the `serde` feature, configurations can be serialized and deserialized, for
+/// example to keep them in a settings file. Missing options take their default.
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::{ShortName, ShortNameConfig};
+/// let config = ShortNameConfig::new().shorten_paths(false);
+/// let name = ShortName("alloc::vec::Vec<my_game::Player>");
+/// assert_eq!(name.with_config(&config).to_string(), "alloc::vec::Vec<my_game::Player>");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ShortNameConfig {
+    pub(crate) shorten_paths: bool,
+    pub(crate) keep_segments: usize,
+    pub(crate) keep_crate: bool,
+    pub(crate) abbreviate_modules: bool,
+    pub(crate) generic_args: GenericArgsStyle,
+    pub(crate) max_generic_depth: usize,
+    pub(crate) deep_generic_args: GenericArgsStyle,
+    pub(crate) max_tuple_len: usize,
+    pub(crate) long_tuples: TupleStyle,
+    pub(crate) max_len: usize,
+    pub(crate) ellipsis_position: EllipsisPosition,
+    pub(crate) hash_suffix: bool,
+    pub(crate) line_width: usize,
+    pub(crate) preserved_crates: Vec<String>,
+    pub(crate) collapsed_crates: Vec<String>,
+    pub(crate) aliases: Vec<(String, String)>,
+    pub(crate) closures: ClosureStyle,
+    pub(crate) strip_disambiguators: bool,
+    pub(crate) strip_lifetimes: bool,
+    pub(crate) dyn_keyword: bool,
+    pub(crate) sort_bounds: bool,
+    pub(crate) hide_default_args: bool,
+    pub(crate) keep_outer_paths: bool,
+}
+
+impl ShortNameConfig {
+    /// Creates the default configuration.
+    pub const fn new() -> Self {
+        Self {
+            shorten_paths: true,
+            keep_segments: 1,
+            keep_crate: false,
+            abbreviate_modules: false,
+            generic_args: GenericArgsStyle::Show,
+            max_generic_depth: usize::MAX,
+            deep_generic_args: GenericArgsStyle::Ellipsis,
+            max_tuple_len: usize::MAX,
+            long_tuples: TupleStyle::Truncate,
+            max_len: usize::MAX,
+            ellipsis_position: EllipsisPosition::End,
+            hash_suffix: false,
+            line_width: usize::MAX,
+            preserved_crates: Vec::new(),
+            collapsed_crates: Vec::new(),
+            aliases: Vec::new(),
+            closures: ClosureStyle::Keep,
+            strip_disambiguators: false,
+            strip_lifetimes: false,
+            dyn_keyword: true,
+            sort_bounds: false,
+            hide_default_args: false,
+            keep_outer_paths: false,
+        }
+    }
+
+    /// Creates the configuration bundled by a [`ShortNameStyle`] preset.
+    ///
+    /// The result can be adjusted further with the builder methods.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig, ShortNameStyle};
+    /// let config = ShortNameConfig::from_style(ShortNameStyle::Minimal);
+    /// let name = ShortName("bevy_asset::assets::Assets<alloc::vec::Vec<bevy_scene::Scene<'static>>>");
+    /// assert_eq!(name.with_config(&config).to_string(), "Assets<Vec<…>>");
+    /// ```
+    pub const fn from_style(style: ShortNameStyle) -> Self {
+        match style {
+            ShortNameStyle::Minimal => Self::new()
+                .max_generic_depth(1)
+                .strip_lifetimes(true)
+                .strip_disambiguators(true),
+            ShortNameStyle::Balanced => Self::new(),
+            ShortNameStyle::Verbose => Self::new().keep_crate(true).keep_segments(2),
+        }
+    }
+
+    /// Sets whether module paths are removed from paths. Defaults to `true`.
+    ///
+    /// When `false`, names are rendered in full, so only the other options have
+    /// any effect.
+    pub const fn shorten_paths(mut self, shorten_paths: bool) -> Self {
+        self.shorten_paths = shorten_paths;
+        self
+    }
+
+    /// Sets how many trailing segments of each path are kept when shortening.
+    /// Defaults to `1`.
+    ///
+    /// Keeping more than one segment helps to tell apart items with the same name in
+    /// different modules. As with the default, a segment before the last which starts
+    /// with an uppercase letter is assumed to be an enum and kept regardless, and a
+    /// value of `0` is treated as `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().keep_segments(2);
+    /// let name = ShortName("bevy_render::camera::extract_cameras<bevy_render::camera::Camera3d>");
+    /// assert_eq!(name.with_config(&config).to_string(), "camera::extract_cameras<camera::Camera3d>");
+    /// ```
+    pub const fn keep_segments(mut self, keep_segments: usize) -> Self {
+        self.keep_segments = keep_segments;
+        self
+    }
+
+    /// Sets whether paths outside of generic arguments are kept in full, so that
+    /// only the paths inside generic arguments are shortened. Defaults to `false`.
+    ///
+    /// This keeps the outermost type easy to find while removing the noise from its
+    /// arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().keep_outer_paths(true);
+    /// let name = ShortName("bevy_asset::assets::Assets<bevy_scene::DynamicScene>");
+    /// assert_eq!(name.with_config(&config).to_string(), "bevy_asset::assets::Assets<DynamicScene>");
+    /// ```
+    pub const fn keep_outer_paths(mut self, keep_outer_paths: bool) -> Self {
+        self.keep_outer_paths = keep_outer_paths;
+        self
+    }
+
+    /// Sets whether the crate a path starts with is kept when shortening.
+    /// Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().keep_crate(true);
+    /// let name = ShortName("bevy_render::camera::Camera");
+    /// assert_eq!(name.with_config(&config).to_string(), "bevy_render::Camera");
+    /// ```
+    pub const fn keep_crate(mut self, keep_crate: bool) -> Self {
+        self.keep_crate = keep_crate;
+        self
+    }
+
+    /// Sets whether the segments removed when shortening are abbreviated to their
+    /// initials instead. Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().abbreviate_modules(true);
+    /// let name = ShortName("bevy_render::camera::extract_cameras");
+    /// assert_eq!(name.with_config(&config).to_string(), "b_r::c::extract_cameras");
+    /// ```
+    pub const fn abbreviate_modules(mut self, abbreviate_modules: bool) -> Self {
+        self.abbreviate_modules = abbreviate_modules;
+        self
+    }
+
+    /// Sets how angle bracketed generic arguments are rendered.
+    /// Defaults to [`GenericArgsStyle::Show`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{GenericArgsStyle, ShortName, ShortNameConfig};
+    /// let name = ShortName("bevy_asset::Assets<bevy_scene::DynamicScene>");
+    /// let hidden = ShortNameConfig::new().generic_args(GenericArgsStyle::Hide);
+    /// assert_eq!(name.with_config(&hidden).to_string(), "Assets");
+    /// let ellipsis = ShortNameConfig::new().generic_args(GenericArgsStyle::Ellipsis);
+    /// assert_eq!(name.with_config(&ellipsis).to_string(), "Assets<…>");
+    /// ```
+    pub const fn generic_args(mut self, generic_args: GenericArgsStyle) -> Self {
+        self.generic_args = generic_args;
+        self
+    }
+
+    /// Sets how deeply generic argument lists may be nested before being rendered
+    /// as set by [`deep_generic_args`](Self::deep_generic_args), which defaults to
+    /// an ellipsis. Defaults to no limit.
+    ///
+    /// This does not apply to names nested too deeply to parse, which are shortened
+    /// as they are, as described for [`ShortName::with_config`](crate::ShortName::with_config).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().max_generic_depth(1);
+    /// let name = ShortName("alloc::vec::Vec<std::collections::HashMap<u32, alloc::string::String>>");
+    /// assert_eq!(name.with_config(&config).to_string(), "Vec<HashMap<…>>");
+    /// ```
+    pub const fn max_generic_depth(mut self, max_generic_depth: usize) -> Self {
+        self.max_generic_depth = max_generic_depth;
+        self
+    }
+
+    /// Sets how generic argument lists nested deeper than
+    /// [`max_generic_depth`](Self::max_generic_depth) are rendered.
+    /// Defaults to [`GenericArgsStyle::Ellipsis`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{GenericArgsStyle, ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new()
+    ///     .max_generic_depth(1)
+    ///     .deep_generic_args(GenericArgsStyle::Count);
+    /// let name = ShortName("alloc::vec::Vec<std::collections::HashMap<u32, alloc::string::String>>");
+    /// assert_eq!(name.with_config(&config).to_string(), "Vec<HashMap<2>>");
+    /// ```
+    pub const fn deep_generic_args(mut self, deep_generic_args: GenericArgsStyle) -> Self {
+        self.deep_generic_args = deep_generic_args;
+        self
+    }
+
+    /// Sets how many elements a tuple may have before it is summarized as set by
+    /// [`long_tuples`](Self::long_tuples). Defaults to no limit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().max_tuple_len(2);
+    /// let name = ShortName("(my_game::move_player, my_game::jump, my_game::fall)");
+    /// assert_eq!(name.with_config(&config).to_string(), "(move_player, jump, …)");
+    /// ```
+    pub const fn max_tuple_len(mut self, max_tuple_len: usize) -> Self {
+        self.max_tuple_len = max_tuple_len;
+        self
+    }
+
+    /// Sets how tuples with more than [`max_tuple_len`](Self::max_tuple_len) elements
+    /// are rendered. Defaults to [`TupleStyle::Truncate`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig, TupleStyle};
+    /// let config = ShortNameConfig::new()
+    ///     .max_tuple_len(2)
+    ///     .long_tuples(TupleStyle::Count);
+    /// let name = ShortName("(my_game::move_player, my_game::jump, my_game::fall)");
+    /// assert_eq!(name.with_config(&config).to_string(), "(3 elements)");
+    /// ```
+    pub const fn long_tuples(mut self, long_tuples: TupleStyle) -> Self {
+        self.long_tuples = long_tuples;
+        self
+    }
+
+    /// Sets the maximum number of characters to render, including the ellipsis
+    /// which replaces the rest. Defaults to no limit.
+    ///
+    /// Names are only ever cut between characters, never inside one. With the
+    /// `unicode-width` feature, lengths are measured in display columns, so that
+    /// wide characters such as most CJK ideographs count twice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{EllipsisPosition, ShortName, ShortNameConfig};
+    /// let name = ShortName("bevy_render::camera::extract_cameras<bevy_render::camera::Camera3d>");
+    /// let config = ShortNameConfig::new().max_len(20);
+    /// assert_eq!(name.with_config(&config).to_string(), "extract_cameras<Cam…");
+    /// let config = config.ellipsis_position(EllipsisPosition::Middle);
+    /// assert_eq!(name.with_config(&config).to_string(), "extract_ca…Camera3d>");
+    /// ```
+    pub const fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Sets which part of a name longer than [`max_len`](Self::max_len) is replaced
+    /// with an ellipsis. Defaults to [`EllipsisPosition::End`].
+    pub const fn ellipsis_position(mut self, ellipsis_position: EllipsisPosition) -> Self {
+        self.ellipsis_position = ellipsis_position;
+        self
+    }
+
+    /// Sets whether names cut to [`max_len`](Self::max_len) end with a short hash
+    /// of the original name, such as `#a3f1`, so that names which are cut to the same
+    /// text can still be told apart. Defaults to `false`.
+    ///
+    /// The hash counts towards the maximum length, and is left out when the maximum
+    /// length leaves no room for it. It is the same on every platform and run.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().max_len(14).hash_suffix(true);
+    /// let first = ShortName("my_game::extract_cameras").with_config(&config).to_string();
+    /// let second = ShortName("my_game::extract_camera_views").with_config(&config).to_string();
+    /// assert_eq!(first, "extract_…#1272");
+    /// assert!(second.starts_with("extract_…#"));
+    /// assert_ne!(first, second);
+    /// ```
+    pub const fn hash_suffix(mut self, hash_suffix: bool) -> Self {
+        self.hash_suffix = hash_suffix;
+        self
+    }
+
+    /// Sets the width past which the generic arguments and tuple elements of a name
+    /// are broken onto lines of their own, indented like `rustfmt` would.
+    /// Defaults to no limit.
+    ///
+    /// Groups are only broken when they do not fit, starting from the outermost one,
+    /// and groups nested more than 16 deep are kept on one line. Names are broken
+    /// after being cut to [`max_len`](Self::max_len), closing any groups left open.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().line_width(30);
+    /// let name = ShortName("bevy_ecs::system::Query<(bevy_ecs::entity::Entity, &mut my_game::Health), bevy_ecs::query::With<my_game::Player>>");
+    /// assert_eq!(
+    ///     name.with_config(&config).to_string(),
+    ///     "Query<\n    (Entity, &mut Health),\n    With<Player>,\n>"
+    /// );
+    /// ```
+    pub const fn line_width(mut self, line_width: usize) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    /// Adds a crate whose paths are always rendered in full, such as one of the
+    /// crates in your own workspace.
+    ///
+    /// A name ending in `*` matches every crate starting with the rest of it, so
+    /// `my_game_*` matches both `my_game_ui` and `my_game_audio`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().preserve_crate("my_game");
+    /// let name = ShortName("bevy_ecs::system::In<my_game::combat::DamageSystem>");
+    /// assert_eq!(name.with_config(&config).to_string(), "In<my_game::combat::DamageSystem>");
+    /// ```
+    pub fn preserve_crate(mut self, crate_name: impl Into<String>) -> Self {
+        self.preserved_crates.push(crate_name.into());
+        self
+    }
+
+    /// Adds a crate whose paths are shortened, leaving all paths from crates which
+    /// were never added in full.
+    ///
+    /// Until a crate is added, paths from every crate are shortened. As with
+    /// [`preserve_crate`](Self::preserve_crate), a name ending in `*` matches
+    /// every crate starting with the rest of it, and preserved crates take
+    /// precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new()
+    ///     .collapse_crate("core")
+    ///     .collapse_crate("alloc")
+    ///     .collapse_crate("std")
+    ///     .collapse_crate("bevy_*");
+    /// let name = ShortName("bevy_ecs::system::Res<fancy_plugin::score::Score>");
+    /// assert_eq!(name.with_config(&config).to_string(), "Res<fancy_plugin::score::Score>");
+    /// ```
+    pub fn collapse_crate(mut self, crate_name: impl Into<String>) -> Self {
+        self.collapsed_crates.push(crate_name.into());
+        self
+    }
+
+    /// Adds an alias which replaces `path`, or the start of any path beginning with
+    /// it, with `display`.
+    ///
+    /// Aliases match the segments of paths regardless of their generic arguments,
+    /// which are kept after the alias. Segments following the alias are rendered
+    /// in full. When several aliases match, the longest wins.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new()
+    ///     .alias("bevy_ecs::system::function_system::FunctionSystem", "fn-system")
+    ///     .alias("my_game::combat", "⚔");
+    /// let name = ShortName("bevy_ecs::system::function_system::FunctionSystem<fn(), my_game::combat::attack>");
+    /// assert_eq!(name.with_config(&config).to_string(), "fn-system<fn(), ⚔::attack>");
+    /// ```
+    pub fn alias(mut self, path: impl Into<String>, display: impl Into<String>) -> Self {
+        self.aliases.push((path.into(), display.into()));
+        self
+    }
+
+    /// Sets whether lifetimes are removed. Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().strip_lifetimes(true);
+    /// let name = ShortName("&'a mut bevy_ecs::system::Res<'static, my_game::Score>");
+    /// assert_eq!(name.with_config(&config).to_string(), "&mut Res<Score>");
+    /// ```
+    pub const fn strip_lifetimes(mut self, strip_lifetimes: bool) -> Self {
+        self.strip_lifetimes = strip_lifetimes;
+        self
+    }
+
+    /// Sets whether trait objects are rendered with the `dyn` keyword.
+    /// Defaults to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().dyn_keyword(false);
+    /// let name = ShortName("alloc::boxed::Box<dyn core::any::Any + core::marker::Send>");
+    /// assert_eq!(name.with_config(&config).to_string(), "Box<Any + Send>");
+    /// ```
+    pub const fn dyn_keyword(mut self, dyn_keyword: bool) -> Self {
+        self.dyn_keyword = dyn_keyword;
+        self
+    }
+
+    /// Sets how closure markers such as `{{closure}}` are rendered.
+    /// Defaults to [`ClosureStyle::Keep`].
+    ///
+    /// Unless closures are kept as is, the segment a closure is defined in is kept
+    /// when shortening, as otherwise nothing would identify it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ClosureStyle, ShortName, ShortNameConfig};
+    /// let name = ShortName("my_game::setup::{{closure}}");
+    /// let config = ShortNameConfig::new().closures(ClosureStyle::Replace("λ".into()));
+    /// assert_eq!(name.with_config(&config).to_string(), "setup::λ");
+    /// let config = ShortNameConfig::new().closures(ClosureStyle::Drop);
+    /// assert_eq!(name.with_config(&config).to_string(), "setup");
+    /// ```
+    pub fn closures(mut self, closures: ClosureStyle) -> Self {
+        self.closures = closures;
+        self
+    }
+
+    /// Sets whether the numbers which newer compilers add to markers are removed,
+    /// so that `{closure#1}` is rendered as `{closure}`. Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().strip_disambiguators(true);
+    /// let name = ShortName("my_game::{impl#0}::spawn::{closure#1}");
+    /// assert_eq!(name.with_config(&config).to_string(), "{closure}");
+    /// ```
+    pub const fn strip_disambiguators(mut self, strip_disambiguators: bool) -> Self {
+        self.strip_disambiguators = strip_disambiguators;
+        self
+    }
+
+    /// Sets whether the bounds of trait objects and `impl Trait` types are put in a
+    /// canonical order, so that the same type is rendered the same way however its
+    /// bounds were written. Defaults to `false`.
+    ///
+    /// Other traits keep their order and come first, followed by the auto traits
+    /// `Send`, `Sync`, `Unpin`, `UnwindSafe` and `RefUnwindSafe`, and then lifetimes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().sort_bounds(true);
+    /// let name = ShortName("dyn core::marker::Sync + core::error::Error + core::marker::Send");
+    /// assert_eq!(name.with_config(&config).to_string(), "dyn Error + Send + Sync");
+    /// ```
+    pub const fn sort_bounds(mut self, sort_bounds: bool) -> Self {
+        self.sort_bounds = sort_bounds;
+        self
+    }
+
+    /// Sets whether trailing generic arguments which the standard library uses as
+    /// defaults are removed. Defaults to `false`.
+    ///
+    /// The defaults recognized are the `Global` allocator, as in `Vec<T, Global>`,
+    /// and the `RandomState` hasher, as in `HashMap<K, V, RandomState>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new().hide_default_args(true);
+    /// let name = ShortName("alloc::vec::Vec<u8, alloc::alloc::Global>");
+    /// assert_eq!(name.with_config(&config).to_string(), "Vec<u8>");
+    /// ```
+    pub const fn hide_default_args(mut self, hide_default_args: bool) -> Self {
+        self.hide_default_args = hide_default_args;
+        self
+    }
+
+    /// Finds the longest alias matching the start of `segments`, returning its
+    /// display string and the number of segments it replaces.
+    pub(crate) fn alias_for(&self, segments: &[PathSegment<'_>]) -> Option<(&str, usize)> {
+        self.aliases
+            .iter()
+            .filter_map(|(path, display)| {
+                let mut len = 0;
+                for ident in path.split("::") {
+                    let segment = segments.get(len)?;
+                    // Only the last segment replaced may have arguments.
+                    if segment.ident != ident || (len > 0 && segments[len - 1].args.is_some()) {
+                        return None;
+                    }
+                    len += 1;
+                }
+                Some((display.as_str(), len))
+            })
+            .max_by_key(|(_, len)| *len)
+    }
+
+    /// Whether paths starting with `crate_name` are shortened.
+    pub(crate) fn shortens(&self, crate_name: Option<&str>) -> bool {
+        let Some(crate_name) = crate_name else {
+            return self.shorten_paths && self.collapsed_crates.is_empty();
+        };
+        let matches = |patterns: &[String]| {
+            patterns
+                .iter()
+                .any(|pattern| crate_matches(pattern, crate_name))
+        };
+        self.shorten_paths
+            && !matches(&self.preserved_crates)
+            && (self.collapsed_crates.is_empty() || matches(&self.collapsed_crates))
+    }
+}
+
+/// Whether `crate_name` matches `pattern`, which may end in a `*` wildcard.
+fn crate_matches(pattern: &str, crate_name: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => crate_name.starts_with(prefix),
+        None => crate_name == pattern,
+    }
+}
+
+/// How [`ShortNameConfig::generic_args`] renders angle bracketed generic arguments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum GenericArgsStyle {
+    /// Renders the arguments, as in `Assets<DynamicScene>`.
+    Show,
+    /// Removes the arguments, as in `Assets`.
+    Hide,
+    /// Replaces the arguments with an ellipsis, as in `Assets<…>`.
+    Ellipsis,
+    /// Replaces the arguments with how many there are, as in `HashMap<2>`.
+    Count,
+}
+
+/// How [`ShortNameConfig::long_tuples`] renders tuples which have too many elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TupleStyle {
+    /// Keeps the first elements and replaces the rest with an ellipsis,
+    /// as in `(move_player, jump, …)`.
+    Truncate,
+    /// Replaces the elements with how many there are, as in `(3 elements)`.
+    Count,
+}
+
+/// Where [`ShortNameConfig::ellipsis_position`] cuts names which are too long.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum EllipsisPosition {
+    /// Keeps the end of the name, as in `…Camera3d>`.
+    Start,
+    /// Keeps the start and end of the name, as in `extract…Camera3d>`.
+    Middle,
+    /// Keeps the start of the name, as in `extract_cameras…`.
+    End,
+}
+
+/// How [`ShortNameConfig::closures`] renders closure markers.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ClosureStyle {
+    /// Renders the marker as it appears in the name, as in `{{closure}}`.
+    Keep,
+    /// Removes the marker, as in `setup`.
+    Drop,
+    /// Replaces the marker with a custom token, as in `setup::λ`.
+    Replace(String),
+}
+
+/// Presets for [`ShortNameConfig::from_style`], bundling options which work well together.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ShortNameStyle {
+    /// Keeps only what is needed to recognize a name: generic arguments nested
+    /// in other generic arguments are elided, and lifetimes and marker numbers
+    /// are removed, as in `Assets<Scene>`.
+    Minimal,
+    /// The default configuration, as in `Assets<Scene<'static>>`.
+    Balanced,
+    /// Keeps the crate and the module each item is defined in, as in
+    /// `bevy_asset::assets::Assets<bevy_scene::scene::Scene<'static>>`.
+    Verbose,
+}
+
+impl From<ShortNameStyle> for ShortNameConfig {
+    fn from(style: ShortNameStyle) -> Self {
+        Self::from_style(style)
+    }
+}
+
+impl Default for ShortNameConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod config_tests {
+    use crate::{
+        ClosureStyle, EllipsisPosition, GenericArgsStyle, ShortName, ShortNameConfig,
+        ShortNameStyle, TupleStyle,
+    };
+    use alloc::string::{String, ToString};
+
+    fn render(name: &str, config: &ShortNameConfig) -> String {
+        ShortName(name).with_config(config).to_string()
+    }
+
+    #[test]
+    fn keep_segments() {
+        let with_segments = |name, keep_segments| {
+            render(name, &ShortNameConfig::new().keep_segments(keep_segments))
+        };
+
+        assert_eq!(with_segments("a::b::c::D<e::f::G, H>", 2), "c::D<f::G, H>");
+        assert_eq!(with_segments("a::b::c::D", 10), "a::b::c::D");
+        assert_eq!(with_segments("a::b::c::D", 0), "D");
+        assert_eq!(
+            with_segments("a::b::C<d::e::F>::g::h", 2),
+            "b::C<e::F>::g::h"
+        );
+        assert_eq!(with_segments("a::b::C::D", 2), "C::D");
+        assert_eq!(with_segments("a::b::C::D", 3), "b::C::D");
+    }
+
+    #[test]
+    fn keep_crate() {
+        let config = ShortNameConfig::new().keep_crate(true);
+
+        assert_eq!(
+            render("bevy_render::RenderSet::Prepare", &config),
+            "bevy_render::RenderSet::Prepare"
+        );
+        assert_eq!(
+            render(
+                "bevy_render::camera::Camera<core::option::Option<u8>>",
+                &config
+            ),
+            "bevy_render::Camera<core::Option<u8>>"
+        );
+        assert_eq!(render("a::B<C>::d::e", &config), "a::B<C>::e");
+        assert_eq!(render("a::B", &config), "a::B");
+        assert_eq!(render("u32", &config), "u32");
+        assert_eq!(
+            render("<a::b::C as d::e::F>::g::h", &config),
+            "<a::C as d::F>::h"
+        );
+        assert_eq!(
+            render("a::b::c::D", &config.clone().keep_segments(2)),
+            "a::c::D"
+        );
+    }
+
+    #[test]
+    fn generic_args() {
+        let hide = ShortNameConfig::new().generic_args(GenericArgsStyle::Hide);
+        let ellipsis = ShortNameConfig::new().generic_args(GenericArgsStyle::Ellipsis);
+        let name = "bevy_asset::Assets<bevy_scene::DynamicScene>::asset_event_system<'a, u8>";
+
+        assert_eq!(render(name, &hide), "Assets::asset_event_system");
+        assert_eq!(render(name, &ellipsis), "Assets<…>::asset_event_system<…>");
+        assert_eq!(
+            render("(a::B<C>, fn(d::E<F>) -> G, dyn h::Fn(I<J>) -> K)", &hide),
+            "(B, fn(E) -> G, dyn Fn(I) -> K)"
+        );
+        assert_eq!(render("a::B<>", &ellipsis), "B<>");
+        assert_eq!(
+            render("<a::B<C> as d::E<F>>::g", &ellipsis),
+            "<B<…> as E<…>>::g"
+        );
+    }
+
+    #[test]
+    fn max_generic_depth() {
+        let depth = |name, max| render(name, &ShortNameConfig::new().max_generic_depth(max));
+        let name = "a::B<c::D<e::F<G>>, (H<I>, [J<K>; 3]), fn(L<M>) -> N<O>>";
+
+        assert_eq!(depth(name, 0), "B<…>");
+        assert_eq!(
+            depth(name, 1),
+            "B<D<…>, (H<…>, [J<…>; 3]), fn(L<…>) -> N<…>>"
+        );
+        assert_eq!(
+            depth(name, 2),
+            "B<D<F<…>>, (H<I>, [J<K>; 3]), fn(L<M>) -> N<O>>"
+        );
+        assert_eq!(depth("a::B<C>::d<E<F>>", 1), "B<C>::d<E<…>>");
+        assert_eq!(
+            render(
+                name,
+                &ShortNameConfig::new()
+                    .max_generic_depth(1)
+                    .generic_args(GenericArgsStyle::Hide)
+            ),
+            "B"
+        );
+    }
+
+    #[test]
+    fn max_len() {
+        let truncate = |name, max_len, position| {
+            let config = ShortNameConfig::new()
+                .max_len(max_len)
+                .ellipsis_position(position);
+            render(name, &config)
+        };
+
+        assert_eq!(truncate("a::Bcdef", 5, EllipsisPosition::End), "Bcdef");
+        assert_eq!(truncate("a::Bcdef", 4, EllipsisPosition::End), "Bcd…");
+        assert_eq!(truncate("a::Bcdef", 4, EllipsisPosition::Start), "…def");
+        assert_eq!(truncate("a::Bcdef", 4, EllipsisPosition::Middle), "Bc…f");
+        assert_eq!(truncate("a::Bcdef", 1, EllipsisPosition::Middle), "…");
+        assert_eq!(truncate("a::Bcdef", 0, EllipsisPosition::End), "");
+        assert_eq!(truncate("a::Ééééé", 3, EllipsisPosition::End), "Éé…");
+        assert_eq!(truncate("a::Ééééé", 3, EllipsisPosition::Start), "…éé");
+        assert_eq!(truncate("a::B<c::D", 3, EllipsisPosition::End), "B<D");
+        assert_eq!(truncate("a::B<c::D", 2, EllipsisPosition::End), "B…");
+    }
+
+    #[test]
+    fn preserve_crate() {
+        let config = ShortNameConfig::new()
+            .preserve_crate("my_game")
+            .preserve_crate("my_plugin_*");
+
+        assert_eq!(
+            render("my_game::combat::DamageSystem", &config),
+            "my_game::combat::DamageSystem"
+        );
+        assert_eq!(
+            render(
+                "bevy_ecs::system::Query<my_plugin_ui::Button, bevy_ecs::query::With<my_game::A>>",
+                &config
+            ),
+            "Query<my_plugin_ui::Button, With<my_game::A>>"
+        );
+        assert_eq!(render("my_games::combat::Damage", &config), "Damage");
+        assert_eq!(
+            render("<my_game::A as bevy_ecs::B>::c::d", &config),
+            "<my_game::A as B>::d"
+        );
+    }
+
+    #[test]
+    fn collapse_crate() {
+        let config = ShortNameConfig::new()
+            .collapse_crate("core")
+            .collapse_crate("bevy_*")
+            .preserve_crate("bevy_internal");
+
+        assert_eq!(
+            render(
+                "bevy_ecs::system::Query<(plugin::a::B, core::option::Option<bevy_internal::C>)>",
+                &config
+            ),
+            "Query<(plugin::a::B, Option<bevy_internal::C>)>"
+        );
+        assert_eq!(render("plugin::a::B", &config), "plugin::a::B");
+        assert_eq!(render("u32", &config), "u32");
+        assert_eq!(
+            render("<plugin::A as core::any::Any>::type_id", &config),
+            "<plugin::A as Any>::type_id"
+        );
+    }
+
+    #[test]
+    fn abbreviate_modules() {
+        let config = ShortNameConfig::new().abbreviate_modules(true);
+
+        assert_eq!(
+            render("bevy_render::RenderSet::Prepare", &config),
+            "b_r::RenderSet::Prepare"
+        );
+        assert_eq!(
+            render(
+                "bevy_asset::assets::Assets<bevy_scene::DynamicScene>::asset_event_system",
+                &config
+            ),
+            "b_a::a::Assets<b_s::DynamicScene>::asset_event_system"
+        );
+        assert_eq!(
+            render("my_game::setup::{{closure}}", &config),
+            "m_g::s::{{closure}}"
+        );
+        assert_eq!(render("a::{impl#0}::work", &config), "a::work");
+        assert_eq!(render("__private::_a__b::C", &config), "p::a_b::C");
+        assert_eq!(
+            render(
+                "bevy_render::camera::Camera",
+                &config.clone().keep_crate(true)
+            ),
+            "bevy_render::c::Camera"
+        );
+    }
+
+    #[test]
+    fn alias() {
+        let config = ShortNameConfig::new()
+            .alias("a::b::C", "c")
+            .alias("a::b", "ab")
+            .alias("a::x::Y::z", "yz");
+
+        assert_eq!(render("a::b::C<d::E>", &config), "c<E>");
+        assert_eq!(render("a::b::D<d::E>", &config), "ab::D<E>");
+        assert_eq!(render("a::b::e::F", &config), "ab::e::F");
+        assert_eq!(render("a::bc::D", &config), "D");
+        assert_eq!(render("a::x::Y<u8>::z", &config), "Y<u8>::z");
+        assert_eq!(render("a::x::Y::z<u8>", &config), "yz<u8>");
+        assert_eq!(render("(a::b::C, &a::b)", &config), "(c, &ab)");
+        assert_eq!(
+            render("<a::b::C as a::b::G>::h", &config),
+            "<c as ab::G>::h"
+        );
+    }
+
+    #[test]
+    fn closures() {
+        let closures = |name, closures| render(name, &ShortNameConfig::new().closures(closures));
+        let name = "my_game::setup::{{closure}}::{closure#1}";
+
+        assert_eq!(closures(name, ClosureStyle::Keep), "{closure#1}");
+        assert_eq!(closures(name, ClosureStyle::Drop), "setup");
+        assert_eq!(
+            closures(name, ClosureStyle::Replace("λ".into())),
+            "setup::λ::λ"
+        );
+        assert_eq!(
+            closures("my_game::Player::{{closure}}", ClosureStyle::Drop),
+            "Player"
+        );
+        assert_eq!(
+            closures("my_game::{impl#0}::run::{{closure}}", ClosureStyle::Drop),
+            "run"
+        );
+        assert_eq!(
+            render(
+                name,
+                &ShortNameConfig::new()
+                    .shorten_paths(false)
+                    .closures(ClosureStyle::Drop)
+            ),
+            "my_game::setup"
+        );
+    }
+
+    #[test]
+    fn strip_lifetimes() {
+        let config = ShortNameConfig::new().strip_lifetimes(true);
+
+        assert_eq!(render("&'a mut foo::T", &config), "&mut T");
+        assert_eq!(render("a::B<'a, 'b>", &config), "B");
+        assert_eq!(render("a::B<>", &config), "B<>");
+        assert_eq!(
+            render(
+                "alloc::boxed::Box<dyn for<'a> a::Fn(&'a u8) + 'static>",
+                &config
+            ),
+            "Box<dyn Fn(&u8)>"
+        );
+        assert_eq!(
+            render("for<'a> fn(&'a str) -> a::B<'a, u8>", &config),
+            "fn(&str) -> B<u8>"
+        );
+        assert_eq!(render("a::B<'a, u8>", &ShortNameConfig::new()), "B<'a, u8>");
+    }
+
+    #[test]
+    fn dyn_keyword() {
+        let config = ShortNameConfig::new().dyn_keyword(false);
+
+        assert_eq!(render("&dyn a::B", &config), "&B");
+        assert_eq!(
+            render("dyn a::Fn(u8) -> u8 + Send", &config),
+            "Fn(u8) -> u8 + Send"
+        );
+        assert_eq!(render("impl a::B", &config), "impl B");
+        assert_eq!(render("&dyn a::B", &ShortNameConfig::new()), "&dyn B");
+    }
+
+    #[test]
+    fn deep_generic_args() {
+        let name = "a::B<c::D<e::F, G>, 'a, H<I<J>>>";
+        let count = ShortNameConfig::new().generic_args(GenericArgsStyle::Count);
+
+        assert_eq!(render(name, &count), "B<3>");
+        assert_eq!(render(name, &count.clone().strip_lifetimes(true)), "B<2>");
+        assert_eq!(
+            render(
+                name,
+                &ShortNameConfig::new()
+                    .max_generic_depth(1)
+                    .deep_generic_args(GenericArgsStyle::Count)
+            ),
+            "B<D<2>, 'a, H<1>>"
+        );
+        assert_eq!(
+            render(
+                name,
+                &ShortNameConfig::new()
+                    .max_generic_depth(1)
+                    .deep_generic_args(GenericArgsStyle::Hide)
+            ),
+            "B<D, 'a, H>"
+        );
+    }
+
+    #[test]
+    fn strip_disambiguators() {
+        let config = ShortNameConfig::new()
+            .shorten_paths(false)
+            .strip_disambiguators(true);
+
+        assert_eq!(
+            render(
+                "my_game::{impl#0}::spawn::{closure#12}::{{closure}}",
+                &config
+            ),
+            "my_game::{impl}::spawn::{closure}::{{closure}}"
+        );
+        assert_eq!(render("[u8; {constant#0}]", &config), "[u8; {constant#0}]");
+        assert_eq!(
+            render(
+                "my_game::spawn::{closure#1}",
+                &config.clone().closures(ClosureStyle::Replace("λ".into()))
+            ),
+            "my_game::spawn::λ"
+        );
+    }
+
+    #[test]
+    fn from_style() {
+        let with_style = |name, style| render(name, &ShortNameConfig::from(style));
+        let name = "bevy_asset::assets::Assets<bevy_scene::scene::Scene<'static>>::{closure#0}";
+
+        assert_eq!(
+            with_style(name, ShortNameStyle::Minimal),
+            "Assets<Scene>::{closure}"
+        );
+        assert_eq!(
+            with_style(name, ShortNameStyle::Balanced),
+            "Assets<Scene<'static>>::{closure#0}"
+        );
+        assert_eq!(
+            with_style(name, ShortNameStyle::Verbose),
+            "bevy_asset::assets::Assets<bevy_scene::scene::Scene<'static>>::{closure#0}"
+        );
+    }
+
+    #[test]
+    fn sort_bounds() {
+        let config = ShortNameConfig::new().sort_bounds(true);
+
+        assert_eq!(
+            render(
+                "alloc::boxed::Box<dyn core::marker::Send + 'static + core::any::Any + core::marker::Sync>",
+                &config
+            ),
+            "Box<dyn Any + Send + Sync + 'static>"
+        );
+        assert_eq!(
+            render(
+                "impl core::marker::Unpin + core::future::Future<Output = ()> + core::marker::Send",
+                &config
+            ),
+            "impl Future<Output = ()> + Send + Unpin"
+        );
+        assert_eq!(
+            render(
+                "dyn core::marker::Sync + core::marker::Send",
+                &ShortNameConfig::new()
+            ),
+            "dyn Sync + Send"
+        );
+    }
+
+    #[test]
+    fn hide_default_args() {
+        let config = ShortNameConfig::new().hide_default_args(true);
+
+        assert_eq!(
+            render(
+                "std::collections::hash::map::HashMap<u32, alloc::vec::Vec<u8, alloc::alloc::Global>, std::hash::random::RandomState>",
+                &config
+            ),
+            "HashMap<u32, Vec<u8>>"
+        );
+        assert_eq!(
+            render("alloc::boxed::Box<dyn core::any::Any, Global>", &config),
+            "Box<dyn Any>"
+        );
+        assert_eq!(
+            render("my_game::Pool<my_game::alloc::Global, u8>", &config),
+            "Pool<Global, u8>"
+        );
+        assert_eq!(
+            render("my_game::Pool<u8, my_game::alloc::Global>", &config),
+            "Pool<u8, Global>"
+        );
+        assert_eq!(
+            render(
+                "alloc::vec::Vec<u8, alloc::alloc::Global>",
+                &ShortNameConfig::new()
+            ),
+            "Vec<u8, Global>"
+        );
+    }
+
+    #[test]
+    fn max_tuple_len() {
+        let tuples = |max_tuple_len, style| {
+            ShortNameConfig::new()
+                .max_tuple_len(max_tuple_len)
+                .long_tuples(style)
+        };
+        let name = "(a::B, (c::D, e::F), [(u8, u16, u32); 2])";
+
+        assert_eq!(
+            render(name, &tuples(3, TupleStyle::Truncate)),
+            "(B, (D, F), [(u8, u16, u32); 2])"
+        );
+        assert_eq!(
+            render(name, &tuples(2, TupleStyle::Truncate)),
+            "(B, (D, F), …)"
+        );
+        assert_eq!(render(name, &tuples(1, TupleStyle::Truncate)), "(B, …)");
+        assert_eq!(render(name, &tuples(0, TupleStyle::Truncate)), "(…)");
+        assert_eq!(render(name, &tuples(2, TupleStyle::Count)), "(3 elements)");
+        assert_eq!(
+            render("[(u8, u16, u32); 2]", &tuples(2, TupleStyle::Count)),
+            "[(3 elements); 2]"
+        );
+        assert_eq!(render("()", &tuples(0, TupleStyle::Count)), "()");
+    }
+
+    #[test]
+    fn keep_outer_paths() {
+        let config = ShortNameConfig::new().keep_outer_paths(true);
+
+        assert_eq!(
+            render(
+                "bevy_asset::assets::Assets<bevy_scene::DynamicScene>::asset_event_system",
+                &config
+            ),
+            "bevy_asset::assets::Assets<DynamicScene>::asset_event_system"
+        );
+        assert_eq!(
+            render("(a::B<c::D<e::F>>, &g::H)", &config),
+            "(a::B<D<F>>, &g::H)"
+        );
+        assert_eq!(
+            render("<a::B<c::D> as e::F<g::H>>::I", &config),
+            "<a::B<D> as e::F<H>>::I"
+        );
+        assert_eq!(
+            render(
+                "a::B<c::D>",
+                &config.clone().generic_args(GenericArgsStyle::Hide)
+            ),
+            "a::B"
+        );
+    }
+
+    #[test]
+    fn line_width() {
+        let with_width = |name, width| render(name, &ShortNameConfig::new().line_width(width));
+        let name = "my_game::Wrapper<(my_game::Alpha, my_game::Beta<my_game::Gamma, [u8; 4]>), fn(u8) -> u8>::run";
+
+        assert_eq!(
+            with_width(name, 60),
+            "Wrapper<(Alpha, Beta<Gamma, [u8; 4]>), fn(u8) -> u8>::run"
+        );
+        assert_eq!(
+            with_width(name, 40),
+            "Wrapper<\n    (Alpha, Beta<Gamma, [u8; 4]>),\n    fn(u8) -> u8,\n>::run"
+        );
+        assert_eq!(
+            with_width(name, 20),
+            concat!(
+                "Wrapper<\n",
+                "    (\n",
+                "        Alpha,\n",
+                "        Beta<\n",
+                "            Gamma,\n",
+                "            [u8; 4],\n",
+                "        >,\n",
+                "    ),\n",
+                "    fn(u8) -> u8,\n",
+                ">::run",
+            )
+        );
+        assert_eq!(
+            with_width("my_game::a_very_long_function_name", 5),
+            "a_very_long_function_name"
+        );
+        assert_eq!(
+            render(
+                "a::B<c::D, e::F>",
+                &ShortNameConfig::new().max_len(6).line_width(4)
+            ),
+            "B<\n    D,\n    …\n>"
+        );
+        assert_eq!(
+            render(
+                "a::B<c::D<e::F, g::H>>",
+                &ShortNameConfig::new().max_len(8).line_width(4)
+            ),
+            "B<\n    D<\n        F,\n        …\n    >\n>"
+        );
+
+        // Groups past the deepest level broken are kept on one line.
+        let depth = 40_000;
+        let name = alloc::format!("{}c::D{}", "a::B<".repeat(depth), ">".repeat(depth));
+        let rendered = with_width(&name, 40);
+        assert_eq!(
+            rendered.lines().count(),
+            2 * crate::layout::MAX_BREAK_DEPTH + 1
+        );
+        assert!(rendered.len() < 4 * name.len());
+    }
+
+    #[test]
+    fn hash_suffix() {
+        let truncate = |name, max_len| {
+            render(
+                name,
+                &ShortNameConfig::new().max_len(max_len).hash_suffix(true),
+            )
+        };
+
+        assert_eq!(truncate("my_game::extract_cameras", 13), "extract…#1272");
+        assert_eq!(truncate("my_game::extract_cameras", 14), "extract_…#1272");
+        assert_eq!(
+            truncate("other_game::extract_cameras", 14),
+            "extract_…#fcf1"
+        );
+        assert_eq!(truncate("my_game::extract_cameras", 6), "…#1272");
+        assert_eq!(truncate("my_game::extract_cameras", 5), "extr…");
+        assert_eq!(truncate("my_game::extract_cameras", 20), "extract_cameras");
+    }
+
+    #[test]
+    fn pathological_names() {
+        let config = ShortNameConfig::new();
+
+        let name = "a::".repeat(10_000) + "B";
+        assert_eq!(render(&name, &config), "B");
+
+        // Too deeply nested to parse, so shortened by the default rules instead,
+        // keeping only the maximum length.
+        let name = "a::B<".repeat(10_000) + &">".repeat(10_000);
+        assert_eq!(
+            render(&name, &config),
+            "B<".repeat(10_000) + &">".repeat(10_000)
+        );
+        let name = "a::B<".repeat(200) + &">".repeat(200);
+        assert_eq!(
+            render(&name, &ShortNameConfig::new().max_generic_depth(2)),
+            "B<".repeat(200) + &">".repeat(200)
+        );
+        assert_eq!(render(&name, &ShortNameConfig::new().max_len(5)), "B<B<…");
+    }
+}
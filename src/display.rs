@@ -0,0 +1,1563 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::EllipsisPosition;
+use crate::ShortName;
+
+/// Displays a [`ShortName`] with all generic arguments removed.
+///
+/// Returned by [`ShortName::without_generics`].
+#[derive(Clone, Copy, Debug)]
+pub struct WithoutGenerics<'a>(pub(crate) ShortName<'a>);
+
+impl<'a> fmt::Display for WithoutGenerics<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, |w| {
+            let mut writer = StripGenerics {
+                inner: w,
+                brackets: 0,
+                depth: 0,
+                generic_depth: 0,
+                previous: ' ',
+            };
+            fmt::write(&mut writer, format_args!("{}", self.0))
+        })
+    }
+}
+
+/// Displays a [`ShortName`] followed by the module the item is in, in parentheses.
+///
+/// Returned by [`ShortName::with_context`].
+#[derive(Clone, Copy, Debug)]
+pub struct WithContext<'a>(pub(crate) ShortName<'a>);
+
+impl<'a> fmt::Display for WithContext<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, |mut w| {
+            self.0.write_to(&mut w)?;
+            match self.0.module_path() {
+                Some(module_path) => write!(w, " ({module_path})"),
+                None => Ok(()),
+            }
+        })
+    }
+}
+
+/// Displays the parts a [`ShortName`] is made of, such as
+/// `crate=bevy_render module=camera ident=extract_cameras generics=[Camera3d]`.
+///
+/// Parts which the name does not have are left out.
+///
+/// Returned by [`ShortName::breakdown`].
+#[derive(Clone, Copy, Debug)]
+pub struct Breakdown<'a>(pub(crate) ShortName<'a>);
+
+impl<'a> fmt::Display for Breakdown<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, |w| {
+            let crate_name = self.0.crate_name();
+            let module = self
+                .0
+                .module_path()
+                .and_then(|module_path| match crate_name {
+                    Some(crate_name) => module_path.strip_prefix(crate_name)?.strip_prefix("::"),
+                    None => Some(module_path),
+                });
+            let mut separator = "";
+            let mut part = |label: &str, value: &dyn fmt::Display| {
+                let result = write!(w, "{separator}{label}={value}");
+                separator = " ";
+                result
+            };
+            if let Some(crate_name) = crate_name {
+                part("crate", &crate_name)?;
+            }
+            if let Some(module) = module {
+                part("module", &module)?;
+            }
+            if let Some(ident) = self.0.ident() {
+                part("ident", &ident)?;
+            }
+            if self.0.generic_args().next().is_some() {
+                let generics = Joined::new(self.0.generic_args(), ", ");
+                part("generics", &format_args!("[{generics}]"))?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Displays a list of names, each shortened, with a separator between them.
+///
+/// Nothing is collected or buffered, so the names are iterated over each time the
+/// list is displayed, which is why the iterator must be [`Clone`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::Joined;
+/// let systems = ["my_game::move_player", "my_game::physics::apply_gravity<f32>"];
+/// assert_eq!(
+///     format!("conflicting systems: {}", Joined::new(systems, ", ")),
+///     "conflicting systems: move_player, apply_gravity<f32>"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Joined<'s, I> {
+    names: I,
+    separator: &'s str,
+}
+
+impl<'s, I> Joined<'s, I> {
+    /// Creates a list of `names` which displays them separated by `separator`.
+    pub const fn new(names: I, separator: &'s str) -> Self {
+        Self { names, separator }
+    }
+}
+
+impl<'a, 's, I> fmt::Display for Joined<'s, I>
+where
+    I: IntoIterator + Clone,
+    I::Item: Into<ShortName<'a>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, |mut w| {
+            for (index, name) in self.names.clone().into_iter().enumerate() {
+                if index > 0 {
+                    w.write_str(self.separator)?;
+                }
+                name.into().write_to(&mut w)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Displays a [`ShortName`] as HTML.
+///
+/// Returned by [`ShortName::html`].
+#[derive(Clone, Copy, Debug)]
+pub struct Html<'a>(pub(crate) ShortName<'a>);
+
+impl<'a> fmt::Display for Html<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write = |mut w: &mut dyn fmt::Write| self.0.write_to(&mut w);
+        let (before, after) = padding(f, write)?;
+        let remaining = f.precision().unwrap_or(usize::MAX);
+        fill(f, before)?;
+        let mut writer = HtmlSpans {
+            inner: &mut *f,
+            open: None,
+            previous: ' ',
+        };
+        write(&mut Limit {
+            inner: &mut writer,
+            remaining,
+            columns: 0,
+        })?;
+        writer.close()?;
+        fill(f, after)
+    }
+}
+
+/// Displays a [`ShortName`] through a callback which writes each [`Piece`] of it.
+///
+/// Returned by [`ShortName::display_with`].
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayWith<'a, F> {
+    pub(crate) name: ShortName<'a>,
+    pub(crate) style: F,
+}
+
+impl<'a, F> fmt::Display for DisplayWith<'a, F>
+where
+    F: Fn(Piece<'_>, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad_styled(f, |mut w| self.name.write_to(&mut w), &self.style)
+    }
+}
+
+/// A run of characters of the same kind in a shortened name, as passed to the
+/// callback of [`ShortName::display_with`].
+///
+/// A name may be split into more pieces than strictly needed, so consecutive
+/// pieces can be of the same kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Piece<'a> {
+    /// What the characters are.
+    pub kind: PieceKind,
+    /// The characters themselves.
+    pub text: &'a str,
+}
+
+/// What the characters of a [`Piece`] are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PieceKind {
+    /// An identifier, keyword, lifetime or literal, such as `Vec`, `dyn`, `'a` or `3`.
+    Ident,
+    /// The brackets around generic arguments, `<` and `>`.
+    Generic,
+    /// Any other punctuation, such as `::`, `,` or `&`.
+    Punct,
+    /// Spaces.
+    Space,
+}
+
+impl PieceKind {
+    /// Classifies `c`, which follows `previous` in a name.
+    fn of(c: char, previous: char) -> Self {
+        match c {
+            ' ' => Self::Space,
+            // The arrow of `fn() -> u8` is not a generic bracket.
+            '>' if previous == '-' => Self::Punct,
+            '<' | '>' => Self::Generic,
+            c if c.is_alphanumeric() || matches!(c, '_' | '\'' | '"') => Self::Ident,
+            _ => Self::Punct,
+        }
+    }
+}
+
+/// Displays a [`ShortName`] colored with ANSI escape codes for terminals, with
+/// identifiers in cyan and punctuation dimmed.
+///
+/// Returned by [`ShortName::colored`].
+#[derive(Clone, Copy, Debug)]
+pub struct Colored<'a> {
+    pub(crate) name: ShortName<'a>,
+    pub(crate) enabled: bool,
+}
+
+impl<'a> Colored<'a> {
+    /// Sets whether the name is colored, rather than displayed as by
+    /// [`ShortName`]. Defaults to `true`.
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Colors the name only if `stream` is a terminal and the `NO_COLOR`
+    /// environment variable is not set to a non-empty value, so one formatting
+    /// call suits both interactive terminals and logs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// # use std::io::Write;
+    /// let mut stderr = std::io::stderr();
+    /// let name = ShortName("bevy_ecs::schedule::Schedule").colored().auto(&stderr);
+    /// writeln!(stderr, "running {name}").unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn auto(self, stream: &impl std::io::IsTerminal) -> Self {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        self.enabled(!no_color && stream.is_terminal())
+    }
+}
+
+impl<'a> fmt::Display for Colored<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.enabled {
+            return fmt::Display::fmt(&self.name, f);
+        }
+        let colored = self.name.display_with(|piece, f| {
+            let color = match piece.kind {
+                PieceKind::Ident => "\x1b[36m",
+                PieceKind::Generic | PieceKind::Punct => "\x1b[2m",
+                _ => return f.write_str(piece.text),
+            };
+            write!(f, "{color}{}\x1b[0m", piece.text)
+        });
+        fmt::Display::fmt(&colored, f)
+    }
+}
+
+/// Displays a [`ShortName`] as a Markdown code span.
+///
+/// Returned by [`ShortName::markdown`].
+#[derive(Clone, Copy, Debug)]
+pub struct Markdown<'a>(pub(crate) ShortName<'a>);
+
+impl<'a> fmt::Display for Markdown<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // A code span cannot be empty or only spaces, so names which shorten to
+        // nothing, or are cut to nothing, are left out.
+        if self.0.chars().all(char::is_whitespace) || f.precision() == Some(0) {
+            return pad(f, |_| Ok(()));
+        }
+
+        // A code span is delimited by a run of backticks longer than any inside it,
+        // and needs spaces to separate it from backticks at either end.
+        let mut longest = 0;
+        let mut run = 0;
+        for c in self.0.chars() {
+            run = if c == '`' { run + 1 } else { 0 };
+            longest = longest.max(run);
+        }
+        let space = if longest > 0 { " " } else { "" };
+        let write = |mut w: &mut dyn fmt::Write| self.0.write_to(&mut w);
+        let (before, after) = padding(f, write)?;
+        let remaining = f.precision().unwrap_or(usize::MAX);
+        fill(f, before)?;
+        for _ in 0..=longest {
+            f.write_str("`")?;
+        }
+        f.write_str(space)?;
+        write(&mut Limit {
+            inner: &mut *f,
+            remaining,
+            columns: 0,
+        })?;
+        f.write_str(space)?;
+        for _ in 0..=longest {
+            f.write_str("`")?;
+        }
+        fill(f, after)
+    }
+}
+
+/// Displays a [`ShortName`] escaped for use in a Graphviz label.
+///
+/// Returned by [`ShortName::dot_label`].
+#[derive(Clone, Copy, Debug)]
+pub struct DotLabel<'a> {
+    pub(crate) name: ShortName<'a>,
+    pub(crate) html: bool,
+}
+
+impl<'a> DotLabel<'a> {
+    /// Sets whether the name is escaped for HTML-like labels, as in `label=<..>`,
+    /// rather than for quoted strings and record labels. Defaults to `false`.
+    pub const fn html(mut self, html: bool) -> Self {
+        self.html = html;
+        self
+    }
+}
+
+impl<'a> fmt::Display for DotLabel<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write = |mut w: &mut dyn fmt::Write| self.name.write_to(&mut w);
+        let (before, after) = padding(f, write)?;
+        let remaining = f.precision().unwrap_or(usize::MAX);
+        fill(f, before)?;
+        write(&mut Limit {
+            inner: DotEscape {
+                inner: &mut *f,
+                html: self.html,
+            },
+            remaining,
+            columns: 0,
+        })?;
+        fill(f, after)
+    }
+}
+
+/// Displays the output of any [`Display`](fmt::Display) type as a [`ShortName`].
+///
+/// This is useful for names which are only available through a [`Display`](fmt::Display)
+/// implementation. The output is written to a buffer first, and then shortened.
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::ShortDisplay;
+/// struct SystemName(&'static str);
+///
+/// impl core::fmt::Display for SystemName {
+///     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+///         f.write_str(self.0)
+///     }
+/// }
+///
+/// let name = SystemName("my_game::systems::move_player");
+/// assert_eq!(ShortDisplay(name).to_string(), "move_player");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ShortDisplay<T>(pub T);
+
+#[cfg(feature = "alloc")]
+impl<T: fmt::Display> fmt::Display for ShortDisplay<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = alloc::string::ToString::to_string(&self.0);
+        fmt::Display::fmt(&ShortName(&name), f)
+    }
+}
+
+/// Displays the [`Debug`](fmt::Debug) output of a value with every path in it shortened.
+///
+/// Paths are shortened like those in a [`ShortName`], wherever they appear, including
+/// inside strings. The alternate flag, as in `{:#}`, uses the pretty-printed output,
+/// and the width and precision pad and cut the output as a whole.
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::ShortDebug;
+/// #[derive(Debug)]
+/// struct Event {
+///     ty: &'static str,
+/// }
+///
+/// let event = Event { ty: "bevy_asset::AssetEvent<bevy_render::texture::Image>" };
+/// assert_eq!(ShortDebug(&event).to_string(), r#"Event { ty: "AssetEvent<Image>" }"#);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ShortDebug<T>(pub T);
+
+#[cfg(feature = "alloc")]
+impl<T: fmt::Debug> fmt::Display for ShortDebug<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = if f.alternate() {
+            alloc::format!("{:#?}", self.0)
+        } else {
+            alloc::format!("{:?}", self.0)
+        };
+
+        pad(f, |mut w| {
+            let mut rest = text.as_str();
+            while let Some(start) = rest.find(|c| is_path_char(c) || c == '<') {
+                w.write_str(&rest[..start])?;
+                rest = &rest[start..];
+                let span = &rest[..path_span(rest).max(1)];
+                // Spans such as `key:` in `Foo { key: 1 }` are not paths.
+                if span.contains("::") && !span.ends_with(':') {
+                    ShortName(span).write_to(&mut w)?;
+                } else {
+                    w.write_str(span)?;
+                }
+                rest = &rest[span.len()..];
+            }
+            w.write_str(rest)
+        })
+    }
+}
+
+/// Whether `c` can be part of a path outside of its generic arguments.
+#[cfg(feature = "alloc")]
+fn is_path_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | ':')
+}
+
+/// Gets the length of the path at the start of `text`, including any balanced
+/// `<...>` groups in it, so that a path such as `Assets<a::X>::system` is shortened
+/// as a whole.
+#[cfg(feature = "alloc")]
+fn path_span(text: &str) -> usize {
+    let mut end = 0;
+    loop {
+        end += text[end..]
+            .find(|c| !is_path_char(c))
+            .unwrap_or(text.len() - end);
+        match angle_group(&text[end..]) {
+            Some(len) => end += len,
+            None => return end,
+        }
+    }
+}
+
+/// Gets the length of the `<...>` group at the start of `text`, if it is closed
+/// before the end of the string or field it is in.
+#[cfg(feature = "alloc")]
+fn angle_group(text: &str) -> Option<usize> {
+    if !text.starts_with('<') {
+        return None;
+    }
+    let mut depth = 0usize;
+    let mut previous = ' ';
+    for (index, c) in text.char_indices() {
+        match c {
+            '<' => depth += 1,
+            // The `>` of `->` in `fn() -> T` does not close a group.
+            '>' if previous != '-' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index + 1);
+                }
+            }
+            '"' | '\n' => return None,
+            _ => {}
+        }
+        previous = c;
+    }
+    None
+}
+
+/// Displays a [`ShortName`] as a label for people to read, rather than as Rust.
+///
+/// Returned by [`ShortName::label`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+pub struct Label<'a, 'd> {
+    pub(crate) name: ShortName<'a>,
+    pub(crate) acronyms: &'d [&'d str],
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, 'd> Label<'a, 'd> {
+    /// Sets the acronyms which are written as given wherever an identifier has them
+    /// as a word, in any case, such as `HDR` for `HdrTexture`. Defaults to none.
+    ///
+    /// An acronym may be followed by a lowercase `s`, as in `NPCs`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let label = |name| ShortName(name).label().acronyms(&["AABB", "HDR"]).to_string();
+    /// assert_eq!(label("my_game::HdrTexture"), "HDR Texture");
+    /// assert_eq!(label("my_game::compute_aabbs"), "Compute AABBs");
+    /// ```
+    pub const fn acronyms<'e>(self, acronyms: &'e [&'e str]) -> Label<'a, 'e> {
+        Label {
+            name: self.name,
+            acronyms,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, 'd> fmt::Display for Label<'a, 'd> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = alloc::string::ToString::to_string(&self.name);
+        pad(f, |w| crate::label::write_label(w, &text, self.acronyms))
+    }
+}
+
+/// Displays a [`ShortName`] wrapped onto more lines when it is too long.
+///
+/// Returned by [`ShortName::wrapped`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+pub struct Wrapped<'a> {
+    pub(crate) name: ShortName<'a>,
+    pub(crate) width: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> fmt::Display for Wrapped<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = alloc::string::ToString::to_string(&self.name);
+        crate::layout::wrap(f, &text, self.width)
+    }
+}
+
+/// Displays a [`ShortName`] with each segment of its paths changed by a callback.
+///
+/// Returned by [`ShortName::map_segments`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+pub struct MapSegments<'a, F> {
+    pub(crate) name: ShortName<'a>,
+    pub(crate) map: F,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, F> fmt::Display for MapSegments<'a, F>
+where
+    F: Fn(&str) -> alloc::borrow::Cow<'_, str>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use crate::{TokenKind, TypeNameTokens};
+
+        let text = alloc::string::String::from(self.name);
+        pad(f, |w| {
+            let mut tokens = TypeNameTokens::new(&text);
+            while let Some(token) = tokens.next() {
+                match token.kind {
+                    TokenKind::Ident if !is_keyword(token.text) => {
+                        w.write_str(&(self.map)(token.text))?;
+                    }
+                    // Markers such as `{{closure}}` are segments as a whole.
+                    TokenKind::OpenBrace => {
+                        let end = crate::iter::skip_group(&mut tokens).max(token.offset + 1);
+                        w.write_str(&(self.map)(&text[token.offset..end]))?;
+                    }
+                    _ => w.write_str(token.text)?,
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Whether `ident` is a keyword rather than a segment of a path.
+#[cfg(feature = "alloc")]
+fn is_keyword(ident: &str) -> bool {
+    matches!(
+        ident,
+        "as" | "const"
+            | "dyn"
+            | "extern"
+            | "fn"
+            | "for"
+            | "impl"
+            | "mut"
+            | "unsafe"
+            | "true"
+            | "false"
+    )
+}
+
+/// Displays a [`ShortName`] following a [`ShortNameConfig`](crate::ShortNameConfig).
+///
+/// Returned by [`ShortName::with_config`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+pub struct WithConfig<'c, 'a> {
+    pub(crate) name: ShortName<'a>,
+    pub(crate) config: &'c crate::ShortNameConfig,
+}
+
+#[cfg(feature = "alloc")]
+impl<'c, 'a> WithConfig<'c, 'a> {
+    /// Writes the name, ignoring any maximum length.
+    fn write_untruncated(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        match crate::parse::parse(self.name.0) {
+            Ok(ty) => crate::render::Renderer::new(self.config).expr(w, &ty),
+            // Names which cannot be parsed are shortened as best as possible.
+            Err(_) if self.config.shorten_paths => self.name.write_short(w),
+            Err(_) => w.write_str(self.name.0),
+        }
+    }
+
+    /// Writes the name, cut to the maximum length and broken across lines as configured.
+    pub(crate) fn write_to(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        let width = self.config.line_width;
+        if width == usize::MAX {
+            return self.write_truncated(w);
+        }
+        let mut text = alloc::string::String::new();
+        self.write_truncated(&mut text)?;
+        crate::layout::break_lines(w, &text, width)
+    }
+
+    /// Writes the name, cut to the maximum length if it is too long.
+    fn write_truncated(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        let max_len = self.config.max_len;
+        if max_len == usize::MAX {
+            return self.write_untruncated(w);
+        }
+
+        let mut text = alloc::string::String::new();
+        self.write_untruncated(&mut text)?;
+        let len: usize = text.chars().map(char_width).sum();
+        if len <= max_len {
+            return w.write_str(&text);
+        }
+
+        // The ellipsis and any hash count towards the maximum length, but the hash
+        // is left out when there is no room for it.
+        let hashed = self.config.hash_suffix && max_len > HASH_LEN;
+        let Some(kept) = max_len.checked_sub(if hashed { HASH_LEN + 1 } else { 1 }) else {
+            return Ok(());
+        };
+        let (head, tail) = match self.config.ellipsis_position {
+            EllipsisPosition::Start => (0, kept),
+            EllipsisPosition::Middle => (kept - kept / 2, kept / 2),
+            EllipsisPosition::End => (kept, 0),
+        };
+        let mut used = 0;
+        let head_end = text
+            .char_indices()
+            .find(|&(_, c)| {
+                used += char_width(c);
+                used > head
+            })
+            .map_or(text.len(), |(i, _)| i);
+        let mut used = 0;
+        let tail_start = text
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| {
+                used += char_width(c);
+                used > tail
+            })
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        w.write_str(&text[..head_end])?;
+        w.write_str("…")?;
+        w.write_str(&text[tail_start..])?;
+        if hashed {
+            write!(w, "#{:04x}", hash(self.name.0))?;
+        }
+        Ok(())
+    }
+}
+
+/// The length of the suffix added by [`ShortNameConfig::hash_suffix`](crate::ShortNameConfig::hash_suffix),
+/// such as `#a3f1`.
+#[cfg(feature = "alloc")]
+const HASH_LEN: usize = 5;
+
+/// Hashes `name` with 32 bit FNV-1a, folded to 16 bits, which is stable across
+/// platforms and versions of Rust.
+#[cfg(feature = "alloc")]
+fn hash(name: &str) -> u16 {
+    let hash = name.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    (hash >> 16) as u16 ^ hash as u16
+}
+
+#[cfg(feature = "alloc")]
+impl<'c, 'a> fmt::Display for WithConfig<'c, 'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(f, |w| self.write_to(w))
+    }
+}
+
+/// Writes the text produced by `write`, honoring the width, fill, alignment and
+/// precision of `f` in the same way as [`fmt::Formatter::pad`] does for strings,
+/// except that the width is measured in columns as per [`char_width`].
+///
+/// Without a width or precision the text is written directly. Otherwise `write`
+/// is called twice: once to measure the text and once to write it.
+pub(crate) fn pad(
+    f: &mut fmt::Formatter<'_>,
+    write: impl Fn(&mut dyn fmt::Write) -> fmt::Result,
+) -> fmt::Result {
+    if f.width().is_none() && f.precision().is_none() {
+        return write(f);
+    }
+
+    let (before, after) = padding(f, &write)?;
+    let remaining = f.precision().unwrap_or(usize::MAX);
+    fill(f, before)?;
+    write(&mut Limit {
+        inner: &mut *f,
+        remaining,
+        columns: 0,
+    })?;
+    fill(f, after)
+}
+
+/// Pads and cuts the text written by `write` as [`pad`] does, but passes the text
+/// which is kept on to `style` in [`Piece`]s, so that any markup or escape codes
+/// it adds are not counted towards the width.
+fn pad_styled<F>(
+    f: &mut fmt::Formatter<'_>,
+    write: impl Fn(&mut dyn fmt::Write) -> fmt::Result,
+    style: &F,
+) -> fmt::Result
+where
+    F: Fn(Piece<'_>, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    let (before, after) = padding(f, &write)?;
+    let remaining = f.precision().unwrap_or(usize::MAX);
+    fill(f, before)?;
+    write(&mut Limit {
+        inner: Pieces {
+            f: &mut *f,
+            style,
+            previous: ' ',
+        },
+        remaining,
+        columns: 0,
+    })?;
+    fill(f, after)
+}
+
+/// Gets how many fill characters go before and after the text written by `write`
+/// to pad it to the width of `f`.
+fn padding(
+    f: &fmt::Formatter<'_>,
+    write: impl Fn(&mut dyn fmt::Write) -> fmt::Result,
+) -> Result<(usize, usize), fmt::Error> {
+    let limit = f.precision().unwrap_or(usize::MAX);
+    let padding = match f.width() {
+        Some(width) => {
+            let mut counter = Limit {
+                inner: Discard,
+                remaining: limit,
+                columns: 0,
+            };
+            write(&mut counter)?;
+            width.saturating_sub(counter.columns)
+        }
+        None => 0,
+    };
+    Ok(match f.align() {
+        None | Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+    })
+}
+
+/// Writes `count` of the fill character of `f`.
+fn fill(f: &mut fmt::Formatter<'_>, count: usize) -> fmt::Result {
+    let fill = f.fill();
+    (0..count).try_for_each(|_| fmt::Write::write_char(f, fill))
+}
+
+/// How many columns `c` takes up when displayed, such as two for most CJK
+/// ideographs and none for combining marks.
+#[cfg(feature = "unicode-width")]
+fn char_width(c: char) -> usize {
+    unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+}
+
+/// How many columns `c` takes up when displayed, which is assumed to be one
+/// without the `unicode-width` feature.
+#[cfg(not(feature = "unicode-width"))]
+fn char_width(_: char) -> usize {
+    1
+}
+
+/// Passes on at most `remaining` characters written through it, dropping the rest.
+struct Limit<W> {
+    inner: W,
+    remaining: usize,
+    /// How many columns the characters passed on take up, as per [`char_width`].
+    columns: usize,
+}
+
+impl<W: fmt::Write> fmt::Write for Limit<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = match s.char_indices().nth(self.remaining) {
+            Some((end, _)) => end,
+            None => s.len(),
+        };
+        self.remaining -= s[..end].chars().count();
+        self.columns += s[..end].chars().map(char_width).sum::<usize>();
+        self.inner.write_str(&s[..end])
+    }
+}
+
+/// Escapes the text written through it as HTML, wrapping runs of characters of the
+/// same [`PieceKind`] in a `<span>`.
+struct HtmlSpans<W> {
+    inner: W,
+    /// The kind of the `<span>` which is currently open, if any.
+    open: Option<PieceKind>,
+    previous: char,
+}
+
+impl<W: fmt::Write> HtmlSpans<W> {
+    /// Closes the `<span>` which is currently open, if any.
+    fn close(&mut self) -> fmt::Result {
+        if self.open.take().is_some() {
+            self.inner.write_str("</span>")?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for HtmlSpans<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let kind = PieceKind::of(c, self.previous);
+            self.previous = c;
+
+            let class = match kind {
+                PieceKind::Ident => "ident",
+                PieceKind::Generic => "generic",
+                PieceKind::Punct => "punct",
+                PieceKind::Space => "",
+            };
+            let kind = (kind != PieceKind::Space).then_some(kind);
+            if kind != self.open {
+                self.close()?;
+                if kind.is_some() {
+                    write!(self.inner, "<span class=\"{class}\">")?;
+                    self.open = kind;
+                }
+            }
+            match c {
+                '<' => self.inner.write_str("&lt;")?,
+                '>' => self.inner.write_str("&gt;")?,
+                '&' => self.inner.write_str("&amp;")?,
+                '"' => self.inner.write_str("&quot;")?,
+                '\'' => self.inner.write_str("&#39;")?,
+                c => self.inner.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Escapes the text written through it for a Graphviz label.
+struct DotEscape<W> {
+    inner: W,
+    /// Whether to escape for HTML-like labels rather than quoted strings.
+    html: bool,
+}
+
+impl<W: fmt::Write> fmt::Write for DotEscape<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match (self.html, c) {
+                (true, '<') => self.inner.write_str("&lt;")?,
+                (true, '>') => self.inner.write_str("&gt;")?,
+                (true, '&') => self.inner.write_str("&amp;")?,
+                (true, '"') => self.inner.write_str("&quot;")?,
+                (false, '<' | '>' | '{' | '}' | '|' | '"' | '\\') => {
+                    self.inner.write_char('\\')?;
+                    self.inner.write_char(c)?;
+                }
+                _ => self.inner.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Passes the text written through it to a [`ShortName::display_with`] callback,
+/// split into [`Piece`]s.
+struct Pieces<'f, 'b, F> {
+    f: &'f mut fmt::Formatter<'b>,
+    style: &'f F,
+    previous: char,
+}
+
+impl<'f, 'b, F> fmt::Write for Pieces<'f, 'b, F>
+where
+    F: Fn(Piece<'_>, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut start = 0;
+        let mut current = None;
+        for (i, c) in s.char_indices() {
+            let kind = PieceKind::of(c, self.previous);
+            self.previous = c;
+            if current != Some(kind) {
+                if let Some(kind) = current {
+                    (self.style)(
+                        Piece {
+                            kind,
+                            text: &s[start..i],
+                        },
+                        self.f,
+                    )?;
+                }
+                start = i;
+                current = Some(kind);
+            }
+        }
+        match current {
+            Some(kind) => (self.style)(
+                Piece {
+                    kind,
+                    text: &s[start..],
+                },
+                self.f,
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Copies the text written through it to `buffer`, counting the length of the whole
+/// text even once `buffer` is full.
+pub(crate) struct SliceWriter<'b> {
+    pub(crate) buffer: &'b mut [u8],
+    /// The length of the text written so far, whether it fit or not.
+    pub(crate) len: usize,
+    /// Whether any of the text did not fit.
+    pub(crate) overflowed: bool,
+}
+
+impl<'b> fmt::Write for SliceWriter<'b> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        match self.buffer.get_mut(self.len..end) {
+            Some(target) if !self.overflowed => target.copy_from_slice(s.as_bytes()),
+            _ => self.overflowed = true,
+        }
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Checks that everything written through it matches `expected`, without allocating.
+pub(crate) struct Compare<'a> {
+    pub(crate) expected: &'a str,
+    /// The number of bytes matched so far.
+    pub(crate) offset: usize,
+}
+
+impl<'a> fmt::Write for Compare<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let rest = &self.expected.as_bytes()[self.offset..];
+        let matched = rest
+            .iter()
+            .zip(s.as_bytes())
+            .take_while(|(expected, actual)| expected == actual)
+            .count();
+        self.offset += matched;
+        if matched == s.len() {
+            Ok(())
+        } else {
+            Err(fmt::Error)
+        }
+    }
+}
+
+/// Measures the text written through it, dropping the text itself.
+#[derive(Default)]
+pub(crate) struct Measure {
+    pub(crate) bytes: usize,
+    pub(crate) chars: usize,
+}
+
+impl fmt::Write for Measure {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.bytes += s.len();
+        self.chars += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Drops everything written through it.
+struct Discard;
+
+impl fmt::Write for Discard {
+    fn write_str(&mut self, _: &str) -> fmt::Result {
+        Ok(())
+    }
+}
+
+/// Drops everything inside `<..>` generic argument lists written through it.
+struct StripGenerics<W> {
+    inner: W,
+    /// For each open `<`, whether it started a generic argument list,
+    /// as opposed to a qualified path such as `<T as Trait>::method`.
+    brackets: u64,
+    depth: u32,
+    generic_depth: u32,
+    previous: char,
+}
+
+impl<W: fmt::Write> fmt::Write for StripGenerics<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let visible = match c {
+                '<' => {
+                    let generic = self.previous.is_alphanumeric()
+                        || matches!(self.previous, '_' | '}')
+                        || self.depth >= u64::BITS;
+                    if self.depth < u64::BITS {
+                        self.brackets = (self.brackets << 1) | u64::from(generic);
+                    }
+                    self.depth += 1;
+                    self.generic_depth += u32::from(generic);
+                    !generic && self.generic_depth == 0
+                }
+                '>' if self.previous != '-' && self.depth > 0 => {
+                    self.depth -= 1;
+                    let generic = self.depth >= u64::BITS || self.brackets & 1 == 1;
+                    if self.depth < u64::BITS {
+                        self.brackets >>= 1;
+                    }
+                    if generic {
+                        self.generic_depth -= 1;
+                        false
+                    } else {
+                        self.generic_depth == 0
+                    }
+                }
+                _ => self.generic_depth == 0,
+            };
+            self.previous = c;
+            if visible {
+                self.inner.write_char(c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod display_tests {
+    use crate::{Joined, PieceKind, ShortDebug, ShortDisplay, ShortName, ShortNameConfig};
+    use alloc::{format, string::ToString};
+
+    #[test]
+    fn without_generics() {
+        let without_generics = |name| ShortName(name).without_generics().to_string();
+
+        assert_eq!(
+            without_generics("bevy_render::camera::camera::extract_cameras<bevy_render::camera::bundle::Camera3d>"),
+            "extract_cameras"
+        );
+        assert_eq!(
+            without_generics(
+                "bevy::do_mad_science<mad_science::Test<mad_science::Tube>, bavy::TypeSystemAbuse>"
+            ),
+            "do_mad_science"
+        );
+        assert_eq!(
+            without_generics(
+                "bevy_asset::assets::Assets<bevy_scene::DynamicScene>::asset_event_system"
+            ),
+            "Assets::asset_event_system"
+        );
+        assert_eq!(
+            without_generics("(alloc::vec::Vec<u8>, core::option::Option<fn(u8) -> u8>)"),
+            "(Vec, Option)"
+        );
+        assert_eq!(
+            without_generics("<alloc::vec::Vec<u8> as core::default::Default>::default"),
+            "<Vec as Default>::default"
+        );
+        assert_eq!(without_generics("fn(a::B<C>) -> d::E<F>"), "fn(B) -> E");
+    }
+
+    #[cfg(not(disqualified_minimal))]
+    #[test]
+    fn with_config() {
+        let default = ShortNameConfig::new();
+        let full = ShortNameConfig::new().shorten_paths(false);
+        let with_config = |name, config| ShortName(name).with_config(config).to_string();
+
+        for name in [
+            "bevy_render::camera::camera::extract_cameras<bevy_render::camera::bundle::Camera3d>",
+            "bevy_asset::assets::Assets<bevy_scene::DynamicScene>::asset_event_system",
+            "<alloc::vec::Vec<u8> as core::default::Default>::default",
+            "(alloc::string::String, [core::option::Option<u8>; 3])",
+            "bevy_render::RenderSet::Prepare",
+        ] {
+            assert_eq!(
+                with_config(name, &default),
+                ShortName(name).to_string(),
+                "{name}"
+            );
+            assert_eq!(with_config(name, &full), name);
+        }
+        assert_eq!(with_config("a::B<c::D", &default), "B<D");
+        assert_eq!(with_config("a::B<c::D", &full), "a::B<c::D");
+    }
+
+    #[test]
+    fn formatter_flags() {
+        let name = ShortName("bevy_render::camera::Camera3d");
+        let config = ShortNameConfig::new().max_len(5);
+
+        assert_eq!(format!("[{name:>10}]"), "[  Camera3d]");
+        assert_eq!(format!("[{name:<10}]"), "[Camera3d  ]");
+        assert_eq!(format!("[{name:-^11}]"), "[-Camera3d--]");
+        assert_eq!(format!("[{name:4}]"), "[Camera3d]");
+        assert_eq!(format!("[{name:.6}]"), "[Camera]");
+        assert_eq!(format!("[{name:>8.3}]"), "[     Cam]");
+        assert_eq!(format!("[{name:?}]"), "[Camera3d]");
+        assert_eq!(format!("[{:>8}]", name.without_generics()), "[Camera3d]");
+        assert_eq!(format!("[{:>6}]", name.with_config(&config)), "[ Came…]");
+        assert_eq!(format!("[{:.3}]", name.with_config(&config)), "[Cam]");
+        assert_eq!(format!("[{name:#}]"), "[bevy_render::camera::Camera3d]");
+        assert_eq!(format!("[{name:#.11}]"), "[bevy_render]");
+    }
+
+    #[test]
+    fn html() {
+        let html = |name| ShortName(name).html().to_string();
+
+        assert_eq!(html(""), "");
+        assert_eq!(
+            html("&'static my_game::Player"),
+            r#"<span class="punct">&amp;</span><span class="ident">&#39;static</span> <span class="ident">Player</span>"#
+        );
+        assert_eq!(
+            html("fn(u8) -> alloc::vec::Vec<u8>"),
+            concat!(
+                r#"<span class="ident">fn</span><span class="punct">(</span><span class="ident">u8</span>"#,
+                r#"<span class="punct">)</span> <span class="punct">-&gt;</span> <span class="ident">Vec</span>"#,
+                r#"<span class="generic">&lt;</span><span class="ident">u8</span><span class="generic">&gt;</span>"#,
+            )
+        );
+        assert_eq!(
+            html("a::B<c::D<e::F>>::g"),
+            concat!(
+                r#"<span class="ident">B</span><span class="generic">&lt;</span><span class="ident">D</span>"#,
+                r#"<span class="generic">&lt;</span><span class="ident">F</span><span class="generic">&gt;&gt;</span>"#,
+                r#"<span class="punct">::</span><span class="ident">g</span>"#,
+            )
+        );
+        assert_eq!(
+            format!("[{:>6}]", ShortName("a::B<C>").html()),
+            r#"[  <span class="ident">B</span><span class="generic">&lt;</span><span class="ident">C</span><span class="generic">&gt;</span>]"#
+        );
+        assert_eq!(
+            format!("[{:-<4.2}]", ShortName("a::B<C>").html()),
+            r#"[<span class="ident">B</span><span class="generic">&lt;</span>--]"#
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        let markdown = |name| ShortName(name).markdown().to_string();
+
+        assert_eq!(markdown("my_game::Player"), "`Player`");
+        assert_eq!(markdown("a::B<c::D<'_>>"), "`B<D<'_>>`");
+        assert_eq!(markdown("a::B<`c`>"), "`` B<`c`> ``");
+        assert_eq!(markdown("a::B<``c>"), "``` B<``c> ```");
+        assert_eq!(markdown(""), "");
+        assert_eq!(markdown("a::"), "");
+        assert_eq!(markdown("`a`::B"), "`B`");
+
+        let name = ShortName("a::B<c::D>");
+        assert_eq!(format!("[{:>6}]", name.markdown()), "[  `B<D>`]");
+        assert_eq!(format!("[{:-^6.2}]", name.markdown()), "[--`B<`--]");
+        assert_eq!(format!("[{:.0}]", name.markdown()), "[]");
+        assert_eq!(format!("[{:3}]", ShortName("a::").markdown()), "[   ]");
+    }
+
+    #[test]
+    fn dot_label() {
+        let dot_label = |name, html| ShortName(name).dot_label().html(html).to_string();
+
+        assert_eq!(dot_label("my_game::Player", false), "Player");
+        assert_eq!(
+            dot_label("fn(a::B<c::D>) -> [u8; 3]", false),
+            r"fn(B\<D\>) -\> [u8; 3]"
+        );
+        assert_eq!(dot_label("a::B<{ \"|\" }>", false), r#"B\<\{ \"\|\" \}\>"#);
+        assert_eq!(
+            dot_label("fn(a::B<c::D>) -> &[u8; 3]", true),
+            "fn(B&lt;D&gt;) -&gt; &amp;[u8; 3]"
+        );
+
+        let name = ShortName("a::B<c::D>");
+        assert_eq!(format!("[{:>6}]", name.dot_label()), r"[  B\<D\>]");
+        assert_eq!(format!("[{:.2}]", name.dot_label().html(true)), "[B&lt;]");
+    }
+
+    #[test]
+    fn wrapped() {
+        let wrapped = |name, width| ShortName(name).wrapped(width).to_string();
+        let name = "my_game::Wrapper<my_game::Alpha, (u8, u16, u32), my_game::Beta<my_game::Gamma, my_game::Delta>>::run";
+
+        assert_eq!(
+            wrapped(name, 80),
+            "Wrapper<Alpha, (u8, u16, u32), Beta<Gamma, Delta>>::run"
+        );
+        assert_eq!(
+            wrapped(name, 40),
+            "Wrapper<Alpha, (u8, u16, u32),\n        Beta<Gamma, Delta>>::run"
+        );
+        assert_eq!(
+            wrapped(name, 10),
+            "Wrapper<Alpha,\n        (u8, u16, u32),\n        Beta<Gamma, Delta>>::run"
+        );
+        assert_eq!(
+            wrapped("a::B<c::D, e::F>::g<h::I, j::K>", 14),
+            "B<D, F>::g<I,\n           K>"
+        );
+        assert_eq!(wrapped("fn(a::B) -> c::D", 1), "fn(B) -> D");
+        assert_eq!(wrapped("", 0), "");
+
+        // Groups may open further in than a format width can pad to.
+        let long = "x".repeat(70_000);
+        let name = format!("{long}<c::D, e::F>");
+        let padding = " ".repeat(70_001);
+        assert_eq!(wrapped(&name, 80), format!("{long}<D,\n{padding}F>"));
+    }
+
+    #[test]
+    fn short_display() {
+        let name = format_args!("{}::{}<{}>", "my_game", "Wrapper", "alloc::string::String");
+
+        assert_eq!(ShortDisplay(name).to_string(), "Wrapper<String>");
+        assert_eq!(
+            format!("[{:>10}]", ShortDisplay("my_game::Player")),
+            "[    Player]"
+        );
+        assert_eq!(
+            format!("{:#}", ShortDisplay("my_game::Player")),
+            "my_game::Player"
+        );
+        assert_eq!(ShortDisplay(42).to_string(), "42");
+    }
+
+    #[test]
+    fn short_debug() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        enum Event {
+            Added { ty: &'static str, count: u32 },
+            Removed(&'static str),
+        }
+
+        let added = Event::Added {
+            ty: "bevy_asset::AssetEvent<bevy_render::texture::Image>",
+            count: 2,
+        };
+        assert_eq!(
+            ShortDebug(&added).to_string(),
+            r#"Added { ty: "AssetEvent<Image>", count: 2 }"#
+        );
+        assert_eq!(
+            format!("{:#}", ShortDebug(&added)),
+            "Added {\n    ty: \"AssetEvent<Image>\",\n    count: 2,\n}"
+        );
+        #[cfg(not(disqualified_minimal))]
+        assert_eq!(
+            ShortDebug(Event::Removed("bevy_render::RenderSet::Prepare")).to_string(),
+            r#"Removed("RenderSet::Prepare")"#
+        );
+        assert_eq!(
+            ShortDebug(["a::b::C", "d::{{closure}}"]).to_string(),
+            r#"["C", "d::{{closure}}"]"#
+        );
+        assert_eq!(
+            format!("[{:>10}]", ShortDebug(Some("a::B"))),
+            r#"[ Some("B")]"#
+        );
+        assert_eq!(format!("[{:.4}]", ShortDebug(["a::b::C"])), r#"[["C"]"#);
+        #[cfg(not(disqualified_minimal))]
+        assert_eq!(ShortDebug("a:::b").to_string(), r#""b""#);
+
+        assert_eq!(
+            ShortDebug("bevy_asset::Assets<a::X>::system").to_string(),
+            r#""Assets<X>::system""#
+        );
+        assert_eq!(
+            ShortDebug("<a::B as c::D>::e").to_string(),
+            r#""<B as D>::e""#
+        );
+        assert_eq!(
+            ShortDebug(["core::option::Option<u8>::None"]).to_string(),
+            r#"["Option<u8>::None"]"#
+        );
+        assert_eq!(
+            ShortDebug(Some("a::B<fn() -> c::D>")).to_string(),
+            r#"Some("B<fn() -> D>")"#
+        );
+        assert_eq!(ShortDebug("a < b::C").to_string(), r#""a < C""#);
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-width")]
+    fn unicode_width() {
+        let name = ShortName("my_game::敵<my_game::弾>");
+        let truncated = |max_len| {
+            name.with_config(&ShortNameConfig::new().max_len(max_len))
+                .to_string()
+        };
+
+        assert_eq!(format!("[{name:>8}]"), "[  敵<弾>]");
+        assert_eq!(format!("[{name:-^9}]"), "[-敵<弾>--]");
+        assert_eq!(truncated(6), "敵<弾>");
+        assert_eq!(truncated(5), "敵<…");
+        assert_eq!(truncated(4), "敵<…");
+        assert_eq!(truncated(3), "敵…");
+    }
+
+    #[test]
+    fn display_with() {
+        let name = ShortName("fn(a::B<'_>, [u8; 2]) -> c::D<e::F>");
+        let bracketed = name.display_with(|piece, f| match piece.kind {
+            PieceKind::Ident => write!(f, "[{}]", piece.text),
+            PieceKind::Generic => write!(f, "{{{}}}", piece.text),
+            PieceKind::Punct | PieceKind::Space => f.write_str(piece.text),
+        });
+
+        assert_eq!(
+            bracketed.to_string(),
+            "[fn]([B]{<}['_]{>}, [[u8]; [2]]) -> [D]{<}[F]{>}"
+        );
+        assert_eq!(
+            ShortName("")
+                .display_with(|_, _| unreachable!())
+                .to_string(),
+            ""
+        );
+    }
+
+    #[test]
+    fn with_context() {
+        let with_context = |name| ShortName(name).with_context().to_string();
+
+        assert_eq!(with_context("foo::bar::Baz"), "Baz (foo::bar)");
+        #[cfg(not(disqualified_minimal))]
+        assert_eq!(
+            with_context("bevy_render::RenderSet::Prepare"),
+            "RenderSet::Prepare (bevy_render)"
+        );
+        assert_eq!(
+            with_context("my_game::move_player"),
+            "move_player (my_game)"
+        );
+        assert_eq!(with_context("(a::B, c::D)"), "(B, D)");
+        assert_eq!(with_context("<a::B as c::D>::e"), "<B as D>::e");
+        assert_eq!(
+            format!("[{:>12}]", ShortName("a::B").with_context()),
+            "[       B (a)]"
+        );
+    }
+
+    #[test]
+    fn padding_matches_strings() {
+        for name in [
+            "my_game::systems::move_player",
+            "my_game::Größe<my_game::Maß>",
+            "(u8, u16)",
+            "",
+        ] {
+            let name = ShortName(name);
+            let short = name.to_string();
+
+            assert_eq!(format!("{name:<40}|"), format!("{short:<40}|"));
+            assert_eq!(format!("{name:*>12}|"), format!("{short:*>12}|"));
+            assert_eq!(format!("{name:^9.4}|"), format!("{short:^9.4}|"));
+        }
+    }
+
+    #[test]
+    fn joined() {
+        let names = ["a::B", "c::d::E<f::G>", "h::I"];
+        assert_eq!(Joined::new(names, ", ").to_string(), "B, E<G>, I");
+        assert_eq!(
+            Joined::new(names.iter().copied(), " | ").to_string(),
+            "B | E<G> | I"
+        );
+        assert_eq!(
+            Joined::new([ShortName("a::B"), ShortName("c::D")], "").to_string(),
+            "BD"
+        );
+        assert_eq!(Joined::new(["a::B"], ", ").to_string(), "B");
+        assert_eq!(
+            Joined::new(core::iter::empty::<&str>(), ", ").to_string(),
+            ""
+        );
+        assert_eq!(format!("[{:>6}]", Joined::new(names, ",")), "[B,E<G>,I]");
+        assert_eq!(
+            format!("[{:<6}]", Joined::new(["a::B", "c::D"], ", ")),
+            "[B, D  ]"
+        );
+    }
+
+    #[test]
+    fn breakdown() {
+        let breakdown = |name| ShortName(name).breakdown().to_string();
+
+        assert_eq!(
+            breakdown("bevy_render::camera::camera::extract_cameras<bevy_render::camera::bundle::Camera3d>"),
+            "crate=bevy_render module=camera::camera ident=extract_cameras generics=[Camera3d]"
+        );
+        assert_eq!(
+            breakdown("hashbrown::HashMap<u32, alloc::vec::Vec<(u8, u16)>>"),
+            "crate=hashbrown ident=HashMap generics=[u32, Vec<(u8, u16)>]"
+        );
+        assert_eq!(
+            breakdown("bevy_render::RenderSet::Prepare"),
+            "crate=bevy_render ident=Prepare"
+        );
+        assert_eq!(breakdown("u32"), "ident=u32");
+        assert_eq!(breakdown(""), "");
+    }
+
+    #[test]
+    fn label() {
+        let label = |name| ShortName(name).label().to_string();
+
+        assert_eq!(
+            label("bevy_render::camera::camera::extract_cameras<bevy_render::camera::bundle::Camera3d>"),
+            "Extract Cameras (Camera 3d)"
+        );
+        assert_eq!(label("my_game::PlayerHealth"), "Player Health");
+        assert_eq!(label("my_game::GPUMeshData"), "GPU Mesh Data");
+        assert_eq!(label("bevy::Camera2dBundle"), "Camera 2d Bundle");
+        assert_eq!(label("glam::Vec3"), "Vec3");
+        assert_eq!(label("u8"), "U8");
+        assert_eq!(label("my_game::__private_thing"), "Private Thing");
+        assert_eq!(
+            label("std::collections::HashMap<my_game::PlayerId, alloc::vec::Vec<u8>>"),
+            "Hash Map (Player Id, Vec (U8))"
+        );
+        assert_eq!(
+            label("bevy_asset::assets::Assets<bevy_scene::DynamicScene>::asset_event_system"),
+            "Assets (Dynamic Scene) Asset Event System"
+        );
+        assert_eq!(
+            label("(my_game::Player, my_game::Enemy)"),
+            "(Player, Enemy)"
+        );
+        assert_eq!(label("fn(u8) -> my_game::Score"), "Fn(U8) -> Score");
+        assert_eq!(label("my_game::Über"), "Über");
+        assert_eq!(
+            format!("[{:>8}]", ShortName("a::is_ok").label()),
+            "[   Is Ok]"
+        );
+    }
+
+    #[test]
+    fn map_segments() {
+        use alloc::borrow::Cow;
+
+        let upper = |name| {
+            ShortName(name)
+                .map_segments(|segment| Cow::Owned(segment.to_uppercase()))
+                .to_string()
+        };
+        #[cfg(not(disqualified_minimal))]
+        assert_eq!(
+            upper("a::RenderSet::Prepare<&'static mut dyn b::Trait, [u8; 3]>"),
+            "RENDERSET::PREPARE<&'static mut dyn TRAIT, [U8; 3]>"
+        );
+        assert_eq!(upper("a::system::{{closure}}"), "{{CLOSURE}}");
+        assert_eq!(upper("fn(a::B) -> c::D"), "fn(B) -> D");
+        assert_eq!(upper("a::Flag<true>"), "FLAG<true>");
+        assert_eq!(upper(""), "");
+
+        let name = ShortName("a::B<c::D>").map_segments(|segment| match segment {
+            "D" => Cow::Borrowed("Dee"),
+            _ => Cow::Borrowed(segment),
+        });
+        assert_eq!(alloc::format!("[{name:>8}]"), "[  B<Dee>]");
+        assert_eq!(alloc::format!("[{name:.3}]"), "[B<D]");
+    }
+
+    #[test]
+    fn label_acronyms() {
+        let acronyms = ["AABB", "GPU", "HDR", "NPC", "UI"];
+        let label = |name| ShortName(name).label().acronyms(&acronyms).to_string();
+
+        assert_eq!(label("my_game::GltfMeshGPU"), "Gltf Mesh GPU");
+        assert_eq!(label("my_game::GltfMeshGpu"), "Gltf Mesh GPU");
+        assert_eq!(label("my_game::HdrTexture"), "HDR Texture");
+        assert_eq!(label("my_game::update_npcs"), "Update NPCs");
+        assert_eq!(
+            label("my_game::NpcSpawner<my_game::Aabb>"),
+            "NPC Spawner (AABB)"
+        );
+        assert_eq!(label("my_game::UiNode"), "UI Node");
+        assert_eq!(label("my_game::Uint"), "Uint");
+        assert_eq!(label("my_game::Guide"), "Guide");
+        assert_eq!(label("my_game::HDRI"), "HDRI");
+        assert_eq!(ShortName("a::Aabb").label().to_string(), "Aabb");
+        assert_eq!(
+            ShortName("a::Aabb").label().acronyms(&[""]).to_string(),
+            "Aabb"
+        );
+    }
+
+    #[test]
+    fn colored() {
+        let name = ShortName("core::option::Option<my_game::Player>");
+        assert_eq!(
+            name.colored().to_string(),
+            "\x1b[36mOption\x1b[0m\x1b[2m<\x1b[0m\x1b[36mPlayer\x1b[0m\x1b[2m>\x1b[0m"
+        );
+        assert_eq!(
+            ShortName("(a::B, c::D)").colored().to_string(),
+            "\x1b[2m(\x1b[0m\x1b[36mB\x1b[0m\x1b[2m,\x1b[0m \x1b[36mD\x1b[0m\x1b[2m)\x1b[0m"
+        );
+        assert_eq!(name.colored().enabled(false).to_string(), "Option<Player>");
+        assert_eq!(
+            format!("{:>6}", ShortName("a::B").colored().enabled(false)),
+            "     B"
+        );
+
+        // The name lines up the same way whether or not it is colored.
+        assert_eq!(
+            format!("[{:>6}]", ShortName("a::B<C>").colored()),
+            "[  \x1b[36mB\x1b[0m\x1b[2m<\x1b[0m\x1b[36mC\x1b[0m\x1b[2m>\x1b[0m]"
+        );
+        assert_eq!(
+            format!("[{:-<5.3}]", ShortName("a::B<C>").colored()),
+            "[\x1b[36mB\x1b[0m\x1b[2m<\x1b[0m\x1b[36mC\x1b[0m--]"
+        );
+    }
+}
@@ -0,0 +1,206 @@
+/// An error encountered while parsing a type name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    /// The byte offset in the input at which the problem was found.
+    pub offset: usize,
+    /// What went wrong.
+    pub kind: ParseErrorKind,
+}
+
+/// The kind of problem described by a [`ParseError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input ended while more was expected.
+    UnexpectedEnd,
+    /// A token appeared where it was not expected.
+    UnexpectedToken,
+    /// A type was expected, but something else was found.
+    ExpectedType,
+    /// A path segment was expected, but something else was found.
+    ExpectedSegment,
+    /// A complete type was parsed, but input remained after it.
+    TrailingInput,
+    /// An opening bracket was never closed.
+    UnclosedDelimiter,
+    /// A closing bracket did not match the most recent opening bracket.
+    UnmatchedDelimiter,
+    /// A `::` did not join two path segments.
+    StrayPathSeparator,
+    /// Brackets were nested too deeply to check.
+    TooDeep,
+    /// The name parsed, but would not be rendered back byte-for-byte,
+    /// for example because of unusual whitespace.
+    NonCanonical,
+    /// The storage provided to [`parse_into`](crate::parse_into) was too small.
+    CapacityExceeded,
+}
+
+impl ParseError {
+    pub(crate) const fn new(offset: usize, kind: ParseErrorKind) -> Self {
+        Self { offset, kind }
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset)
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+impl core::fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            ParseErrorKind::UnexpectedEnd => "unexpected end of input",
+            ParseErrorKind::UnexpectedToken => "unexpected token",
+            ParseErrorKind::ExpectedType => "expected a type",
+            ParseErrorKind::ExpectedSegment => "expected a path segment",
+            ParseErrorKind::TrailingInput => "unexpected input after type",
+            ParseErrorKind::UnclosedDelimiter => "unclosed delimiter",
+            ParseErrorKind::UnmatchedDelimiter => "unmatched closing delimiter",
+            ParseErrorKind::StrayPathSeparator => "`::` does not separate path segments",
+            ParseErrorKind::TooDeep => "brackets nested too deeply",
+            ParseErrorKind::NonCanonical => "name would not be rendered the same way",
+            ParseErrorKind::CapacityExceeded => "too many nodes for the storage provided",
+        })
+    }
+}
+
+/// An error returned by [`ShortName::shorten_into_slice`](crate::ShortName::shorten_into_slice)
+/// when the buffer is too small for the shortened name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapacityError {
+    /// The number of bytes the shortened name needs.
+    pub required: usize,
+    /// The number of bytes the buffer has.
+    pub available: usize,
+}
+
+impl core::fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "buffer of {} bytes is too small for a name of {} bytes",
+            self.available, self.required
+        )
+    }
+}
+
+impl core::error::Error for CapacityError {}
+
+/// A [`ParseError`] together with the name it was found in, for reporting.
+///
+/// The [`Display`](core::fmt::Display) implementation shows the name with a caret
+/// pointing at the offending position:
+///
+/// ```text
+/// unclosed delimiter at byte 9
+///   bevy::Vec<u32
+///            ^
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::{Error, ShortName};
+/// let name = "bevy::Vec<u32";
+/// let error = ShortName(name).validate().map_err(|error| Error::new(name, error)).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "unclosed delimiter at byte 9\n  bevy::Vec<u32\n           ^"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Error<'a> {
+    name: &'a str,
+    error: ParseError,
+}
+
+impl<'a> Error<'a> {
+    /// Attaches the `name` that `error` was found in.
+    pub const fn new(name: &'a str, error: ParseError) -> Self {
+        Self { name, error }
+    }
+
+    /// Gets the name the error was found in.
+    pub const fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Gets the underlying error, without the name.
+    pub const fn parse_error(&self) -> ParseError {
+        self.error
+    }
+
+    /// Gets what went wrong.
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.error.kind
+    }
+
+    /// Gets the byte offset in the name at which the problem was found.
+    pub const fn offset(&self) -> usize {
+        self.error.offset
+    }
+}
+
+impl<'a> core::fmt::Display for Error<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // The caret is placed by character rather than byte, so that it lines up
+        // under non-ASCII names in a terminal.
+        let column = match self.name.get(..self.error.offset) {
+            Some(before) => before.chars().count(),
+            None => self.error.offset,
+        };
+        // The padding is written a space at a time, as a format width cannot pad
+        // past `u16::MAX` characters.
+        write!(f, "{}\n  {}\n  ", self.error, self.name)?;
+        (0..column).try_for_each(|_| f.write_str(" "))?;
+        f.write_str("^")
+    }
+}
+
+/// There is no [`source`](core::error::Error::source), as the message of the
+/// [`ParseError`] is already part of the [`Display`](core::fmt::Display) output,
+/// and error reports would show it twice otherwise. The [`ParseError`] can still be
+/// got with [`parse_error`](Error::parse_error).
+impl<'a> core::error::Error for Error<'a> {}
+
+#[cfg(all(test, feature = "alloc"))]
+mod error_tests {
+    use super::{Error, ParseError, ParseErrorKind};
+    use alloc::string::ToString;
+
+    #[test]
+    fn caret() {
+        let error =
+            |name, offset, kind| Error::new(name, ParseError::new(offset, kind)).to_string();
+
+        assert_eq!(
+            error("a::B<C>>", 7, ParseErrorKind::UnmatchedDelimiter),
+            "unmatched closing delimiter at byte 7\n  a::B<C>>\n         ^"
+        );
+        assert_eq!(
+            error("", 0, ParseErrorKind::UnexpectedEnd),
+            "unexpected end of input at byte 0\n  \n  ^"
+        );
+        assert_eq!(
+            error("é::$", 4, ParseErrorKind::UnexpectedToken),
+            "unexpected token at byte 4\n  é::$\n     ^"
+        );
+
+        let long = "x".repeat(70_000) + ">";
+        let message = error(&long, 70_000, ParseErrorKind::UnmatchedDelimiter);
+        assert!(message.ends_with(&(" ".repeat(70_002) + "^")));
+    }
+
+    #[test]
+    fn source() {
+        let parse_error = ParseError::new(1, ParseErrorKind::TrailingInput);
+        let error = Error::new("a>", parse_error);
+        assert!(core::error::Error::source(&error).is_none());
+        assert_eq!(error.kind(), ParseErrorKind::TrailingInput);
+        assert_eq!(error.parse_error(), parse_error);
+    }
+}
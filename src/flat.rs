@@ -0,0 +1,256 @@
+use crate::{visit::is_keyword, ParseError, ParseErrorKind, TokenKind, TypeNameTokens};
+
+/// A type name parsed into caller-provided storage by [`parse_into`].
+///
+/// The nodes are stored in the order they appear in the name, with each node
+/// after its parent, so that the tree can be analyzed without allocating.
+#[derive(Clone, Copy, Debug)]
+pub struct FlatTree<'n, 'a> {
+    nodes: &'n [Node<'a>],
+}
+
+impl<'n, 'a> FlatTree<'n, 'a> {
+    /// Gets the nodes of the tree, in the order they appear in the name.
+    pub fn nodes(&self) -> &'n [Node<'a>] {
+        self.nodes
+    }
+
+    /// Gets the direct children of the node at `parent`, or the top-level nodes
+    /// if `parent` is [`None`], along with their indices.
+    pub fn children(
+        &self,
+        parent: Option<usize>,
+    ) -> impl Iterator<Item = (usize, &'n Node<'a>)> + 'n {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(move |(_, node)| node.parent == parent)
+    }
+}
+
+/// A single part of a [`FlatTree`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Node<'a> {
+    /// What the node represents.
+    pub kind: NodeKind,
+    /// The text of the node. For brackets, this includes everything up to and
+    /// including the closing bracket.
+    pub text: &'a str,
+    /// The byte offset of the node in the name.
+    pub offset: usize,
+    /// The index of the enclosing bracket node, if any.
+    pub parent: Option<usize>,
+}
+
+impl<'a> Node<'a> {
+    /// A placeholder node, for initializing storage to pass to [`parse_into`].
+    pub const EMPTY: Self = Self {
+        kind: NodeKind::Segment,
+        text: "",
+        offset: 0,
+        parent: None,
+    };
+}
+
+/// What a [`Node`] represents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NodeKind {
+    /// A path segment, such as `bevy_render`, `Vec` or `{{closure}}`.
+    Segment,
+    /// The generic arguments of a path segment, as in `Vec<u32>`.
+    Generics,
+    /// A qualified path, as in `<T as Trait>::method`.
+    Qualified,
+    /// A tuple or parenthesized type.
+    Tuple,
+    /// Parenthesized arguments, as in `Fn(u32)`, `fn(u32)` or `Some(2)`.
+    Args,
+    /// An array or slice.
+    Array,
+    /// A keyword, such as `dyn`, `mut` or `as`.
+    Keyword,
+    /// A lifetime, such as `'static`.
+    Lifetime,
+    /// A literal, such as the `3` in `[u8; 3]`.
+    Literal,
+}
+
+/// Parses a type name into `nodes`, without allocating.
+///
+/// Punctuation such as `::`, `,` and `&` is not stored, so a name needs at most one
+/// node per identifier, literal or bracket. The name is first checked as by
+/// [`ShortName::validate`](crate::ShortName::validate), and if `nodes` is too
+/// small, an error of kind [`ParseErrorKind::CapacityExceeded`] is returned at
+/// the first part of the name which did not fit.
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::{parse_into, Node, NodeKind};
+/// let mut nodes = [Node::EMPTY; 16];
+/// let tree = parse_into("alloc::vec::Vec<u8>", &mut nodes).unwrap();
+/// let kinds: Vec<_> = tree.nodes().iter().map(|node| node.kind).collect();
+/// assert_eq!(
+///     kinds,
+///     [NodeKind::Segment, NodeKind::Segment, NodeKind::Segment, NodeKind::Generics, NodeKind::Segment]
+/// );
+/// assert_eq!(tree.nodes()[3].text, "<u8>");
+/// assert_eq!(tree.nodes()[4].parent, Some(3));
+/// ```
+pub fn parse_into<'n, 'a>(
+    name: &'a str,
+    nodes: &'n mut [Node<'a>],
+) -> Result<FlatTree<'n, 'a>, ParseError> {
+    crate::validate::validate(name)?;
+
+    let mut len = 0;
+    let mut parent: Option<usize> = None;
+    let mut follows_segment = false;
+    let mut tokens = TypeNameTokens::new(name);
+
+    while let Some(token) = tokens.next() {
+        let mut is_segment = false;
+        let mut text = token.text;
+        let kind = match token.kind {
+            TokenKind::Ident => match token.text {
+                "true" | "false" => NodeKind::Literal,
+                "fn" => {
+                    is_segment = true;
+                    NodeKind::Keyword
+                }
+                text if is_keyword(text) => NodeKind::Keyword,
+                _ => {
+                    is_segment = true;
+                    NodeKind::Segment
+                }
+            },
+            TokenKind::OpenBrace => {
+                // Braced segments such as `{{closure}}` are stored whole.
+                let mut braces = 1_usize;
+                for close in tokens.by_ref() {
+                    match close.kind {
+                        TokenKind::OpenBrace => braces += 1,
+                        TokenKind::CloseBrace => braces -= 1,
+                        _ => {}
+                    }
+                    if braces == 0 {
+                        text = &name[token.offset..close.offset + 1];
+                        break;
+                    }
+                }
+                is_segment = true;
+                NodeKind::Segment
+            }
+            TokenKind::Lifetime => NodeKind::Lifetime,
+            TokenKind::Literal => NodeKind::Literal,
+            TokenKind::Lt if follows_segment => NodeKind::Generics,
+            TokenKind::Lt => NodeKind::Qualified,
+            TokenKind::OpenParen if follows_segment => NodeKind::Args,
+            TokenKind::OpenParen => NodeKind::Tuple,
+            TokenKind::OpenBracket => NodeKind::Array,
+            TokenKind::Gt | TokenKind::CloseParen | TokenKind::CloseBracket => {
+                // Validation guarantees that this closes the current parent.
+                if let Some(open) = parent {
+                    let node = &mut nodes[open];
+                    node.text = &name[node.offset..token.offset + 1];
+                    parent = node.parent;
+                }
+                follows_segment = false;
+                continue;
+            }
+            _ => {
+                follows_segment = false;
+                continue;
+            }
+        };
+
+        let Some(slot) = nodes.get_mut(len) else {
+            return Err(ParseError::new(
+                token.offset,
+                ParseErrorKind::CapacityExceeded,
+            ));
+        };
+        *slot = Node {
+            kind,
+            text,
+            offset: token.offset,
+            parent,
+        };
+        if matches!(
+            kind,
+            NodeKind::Generics
+                | NodeKind::Qualified
+                | NodeKind::Tuple
+                | NodeKind::Args
+                | NodeKind::Array
+        ) {
+            parent = Some(len);
+        }
+        len += 1;
+        follows_segment = is_segment;
+    }
+
+    Ok(FlatTree {
+        nodes: &nodes[..len],
+    })
+}
+
+#[cfg(test)]
+mod flat_tests {
+    use super::{parse_into, Node, NodeKind};
+    use crate::{ParseError, ParseErrorKind};
+
+    #[test]
+    fn tree() {
+        let mut nodes = [Node::EMPTY; 16];
+        let tree = parse_into("<&'a [u8; 3] as a::B(C)>::{{closure}}", &mut nodes).unwrap();
+        let node = |kind, text, offset, parent| Node {
+            kind,
+            text,
+            offset,
+            parent,
+        };
+
+        assert_eq!(
+            tree.nodes(),
+            [
+                node(NodeKind::Qualified, "<&'a [u8; 3] as a::B(C)>", 0, None),
+                node(NodeKind::Lifetime, "'a", 2, Some(0)),
+                node(NodeKind::Array, "[u8; 3]", 5, Some(0)),
+                node(NodeKind::Segment, "u8", 6, Some(2)),
+                node(NodeKind::Literal, "3", 10, Some(2)),
+                node(NodeKind::Keyword, "as", 13, Some(0)),
+                node(NodeKind::Segment, "a", 16, Some(0)),
+                node(NodeKind::Segment, "B", 19, Some(0)),
+                node(NodeKind::Args, "(C)", 20, Some(0)),
+                node(NodeKind::Segment, "C", 21, Some(8)),
+                node(NodeKind::Segment, "{{closure}}", 26, None),
+            ]
+        );
+
+        let mut children = tree.children(Some(2)).map(|(index, _)| index);
+        assert_eq!(children.next(), Some(3));
+        assert_eq!(children.next(), Some(4));
+        assert_eq!(children.next(), None);
+        assert_eq!(tree.children(None).count(), 2);
+    }
+
+    #[test]
+    fn errors() {
+        let mut nodes = [Node::EMPTY; 4];
+        assert_eq!(
+            parse_into("a::b::c::D<E>", &mut nodes).unwrap_err(),
+            ParseError::new(10, ParseErrorKind::CapacityExceeded)
+        );
+        assert_eq!(
+            parse_into("a::B<C", &mut nodes).unwrap_err(),
+            ParseError::new(4, ParseErrorKind::UnclosedDelimiter)
+        );
+        assert_eq!(
+            parse_into("a::b::c::d", &mut nodes).unwrap().nodes().len(),
+            4
+        );
+        assert!(parse_into("a", &mut []).is_err());
+    }
+}
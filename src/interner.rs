@@ -0,0 +1,196 @@
+use alloc::{collections::BTreeMap, string::String, sync::Arc, vec::Vec};
+
+use crate::ShortName;
+
+/// Stores each distinct shortened name once, handing out [`InternedName`]s which
+/// refer to them.
+///
+/// Many full names can shorten to the same name, such as the systems of different
+/// plugins, so this keeps a single copy of each no matter how often it is added.
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::{ShortName, ShortNameInterner};
+/// let mut interner = ShortNameInterner::new();
+/// let first = interner.intern(ShortName("my_game::player::update"));
+/// let second = interner.intern(ShortName("my_game::enemy::update"));
+/// assert_eq!(first, second);
+/// assert_eq!(interner.resolve(first), "update");
+/// assert_eq!(interner.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ShortNameInterner {
+    names: Vec<Arc<str>>,
+    lookup: BTreeMap<Arc<str>, InternedName>,
+    buffer: String,
+}
+
+/// A reference to a name stored in a [`ShortNameInterner`].
+///
+/// Handles are only meaningful to the interner which handed them out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InternedName(u32);
+
+impl InternedName {
+    /// Gets the position of the name in the interner, counting from zero in the
+    /// order names were first added.
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl ShortNameInterner {
+    /// Creates an interner with no names in it.
+    pub const fn new() -> Self {
+        Self {
+            names: Vec::new(),
+            lookup: BTreeMap::new(),
+            buffer: String::new(),
+        }
+    }
+
+    /// Shortens `name` and adds it, unless the same shortened name was added
+    /// before, returning the handle for it either way.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than [`u32::MAX`] distinct names are added.
+    pub fn intern(&mut self, name: ShortName<'_>) -> InternedName {
+        // The buffer is reused so that names already added need no allocation.
+        let mut buffer = core::mem::take(&mut self.buffer);
+        name.shorten_into(&mut buffer);
+        let handle = self.intern_short(&buffer);
+        self.buffer = buffer;
+        handle
+    }
+
+    /// Adds `short_name`, which is already shortened, unless it was added before,
+    /// returning the handle for it either way.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than [`u32::MAX`] distinct names are added.
+    pub fn intern_short(&mut self, short_name: &str) -> InternedName {
+        if let Some(&handle) = self.lookup.get(short_name) {
+            return handle;
+        }
+        let index = u32::try_from(self.names.len()).expect("too many names to intern");
+        let handle = InternedName(index);
+        let short_name: Arc<str> = Arc::from(short_name);
+        self.names.push(short_name.clone());
+        self.lookup.insert(short_name, handle);
+        handle
+    }
+
+    /// Shortens and adds every name in `names`, returning their handles in the
+    /// same order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortNameInterner;
+    /// let mut interner = ShortNameInterner::new();
+    /// let handles = interner.intern_all(["a::Position", "b::Velocity", "c::Position"]);
+    /// assert_eq!(handles[0], handles[2]);
+    /// assert_eq!(interner.len(), 2);
+    /// ```
+    pub fn intern_all<'a, I>(&mut self, names: I) -> Vec<InternedName>
+    where
+        I: IntoIterator,
+        I::Item: Into<ShortName<'a>>,
+    {
+        names
+            .into_iter()
+            .map(|name| self.intern(name.into()))
+            .collect()
+    }
+
+    /// Gets the shortened name which `handle` refers to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` was handed out by an interner with more names.
+    pub fn resolve(&self, handle: InternedName) -> &str {
+        &self.names[handle.index()]
+    }
+
+    /// Gets the handle of `short_name`, if it was added.
+    pub fn get(&self, short_name: &str) -> Option<InternedName> {
+        self.lookup.get(short_name).copied()
+    }
+
+    /// Gets the number of distinct names added.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Whether no names were added.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Gets an iterator over the handles and names added, in the order they were
+    /// first added.
+    pub fn iter(&self) -> impl Iterator<Item = (InternedName, &str)> {
+        self.names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (InternedName(index as u32), &**name))
+    }
+}
+
+impl<'a, T: Into<ShortName<'a>>> Extend<T> for ShortNameInterner {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, names: I) {
+        for name in names {
+            self.intern(name.into());
+        }
+    }
+}
+
+impl<'a, T: Into<ShortName<'a>>> FromIterator<T> for ShortNameInterner {
+    fn from_iter<I: IntoIterator<Item = T>>(names: I) -> Self {
+        let mut interner = Self::new();
+        interner.extend(names);
+        interner
+    }
+}
+
+#[cfg(test)]
+mod interner_tests {
+    use super::ShortNameInterner;
+    use crate::ShortName;
+
+    #[test]
+    fn deduplicates_shortened_names() {
+        let mut interner = ShortNameInterner::new();
+        let a = interner.intern(ShortName("a::b::System<c::D>"));
+        let b = interner.intern(ShortName("e::System<f::D>"));
+        let c = interner.intern(ShortName("e::Other"));
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(interner.resolve(a), "System<D>");
+        assert_eq!(interner.resolve(c), "Other");
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.get("Other"), Some(c));
+        assert_eq!(interner.get("e::Other"), None);
+        assert_eq!(a.index(), 0);
+        assert_eq!(c.index(), 1);
+    }
+
+    #[test]
+    fn bulk_insertion() {
+        let interner: ShortNameInterner = ["a::X", "b::Y", "c::X"].into_iter().collect();
+        let names: alloc::vec::Vec<_> = interner.iter().map(|(_, name)| name).collect();
+        assert_eq!(names, ["X", "Y"]);
+
+        let mut interner = ShortNameInterner::new();
+        interner.extend([ShortName("a::Y")]);
+        let handles = interner.intern_all(["a::X", "b::Y"]);
+        assert_eq!(handles[1].index(), 0);
+        assert_eq!(interner.intern_short("X"), handles[0]);
+        assert!(!interner.is_empty());
+        assert!(ShortNameInterner::default().is_empty());
+    }
+}
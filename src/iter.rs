@@ -0,0 +1,895 @@
+use crate::{
+    short_name::{Chunks, Output},
+    tokens::{Token, TokenKind, TypeNameTokens},
+    ShortName,
+};
+
+/// An iterator over the [`char`]s of a shortened name.
+///
+/// Returned by [`ShortName::chars`].
+#[derive(Clone, Debug)]
+pub struct Chars<'a>(CharsInner<'a>);
+
+#[derive(Clone, Debug)]
+enum CharsInner<'a> {
+    /// Shortened by the default rules as the characters are taken.
+    Short {
+        chunks: Chunks<'a>,
+        current: core::str::Chars<'a>,
+    },
+    /// Rendered following the default configuration.
+    #[cfg(feature = "std")]
+    Rendered {
+        text: alloc::string::String,
+        offset: usize,
+    },
+}
+
+impl<'a> Chars<'a> {
+    pub(crate) fn new(name: ShortName<'a>) -> Self {
+        Self(match name.output() {
+            Output::Short(chunks) => CharsInner::Short {
+                chunks,
+                current: "".chars(),
+            },
+            #[cfg(feature = "std")]
+            Output::Rendered(text) => CharsInner::Rendered { text, offset: 0 },
+        })
+    }
+}
+
+impl<'a> Iterator for Chars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            CharsInner::Short { chunks, current } => loop {
+                if let Some(c) = current.next() {
+                    return Some(c);
+                }
+                *current = chunks.next()?.chars();
+            },
+            #[cfg(feature = "std")]
+            CharsInner::Rendered { text, offset } => {
+                let c = text[*offset..].chars().next()?;
+                *offset += c.len_utf8();
+                Some(c)
+            }
+        }
+    }
+}
+
+impl<'a> core::iter::FusedIterator for Chars<'a> {}
+
+/// An iterator over the segments of the outermost path of a name.
+///
+/// Returned by [`ShortName::segments`].
+#[derive(Clone, Debug)]
+pub struct Segments<'a> {
+    name: &'a str,
+    tokens: TypeNameTokens<'a>,
+    /// The opening token of the arguments of the last segment returned.
+    args: Option<Token<'a>>,
+    /// Whether the path is relative to a type, as in `<T as Trait>::method`.
+    qualified: bool,
+    done: bool,
+}
+
+impl<'a> Segments<'a> {
+    pub(crate) fn new(name: &'a str) -> Self {
+        let mut tokens = TypeNameTokens::new(name);
+        let mut qualified = false;
+        let done = match peek(&tokens) {
+            // A qualified path such as `<T as Trait>::method`, or a path following a
+            // tuple or array such as `(String, String)::default`.
+            Some(token) if is_open(token.kind) && token.kind != TokenKind::OpenBrace => {
+                tokens.next();
+                skip_group(&mut tokens);
+                qualified = true;
+                !eat(&mut tokens, TokenKind::PathSep)
+            }
+            Some(token) if token.kind == TokenKind::Ident => is_type_keyword(token.text),
+            Some(token) => token.kind != TokenKind::OpenBrace,
+            None => true,
+        };
+        Self {
+            name,
+            tokens,
+            args: None,
+            qualified,
+            done,
+        }
+    }
+
+    /// Whether the path is relative to a type rather than a crate,
+    /// as in `<T as Trait>::method`.
+    pub(crate) fn is_qualified(&self) -> bool {
+        self.qualified
+    }
+
+    /// Whether the last segment returned had generic or parenthesized arguments.
+    pub(crate) fn had_args(&self) -> bool {
+        self.args.is_some()
+    }
+
+    /// Whether the last segment returned was the final segment of the path.
+    pub(crate) fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// If the path ends in angle bracketed generic arguments with nothing after them,
+    /// gets the part of the name before them, such as `alloc::sync::Arc` for
+    /// `alloc::sync::Arc<T>`.
+    pub(crate) fn generic_base(mut self) -> Option<&'a str> {
+        if self.qualified {
+            return None;
+        }
+        while self.next_spanned().is_some() {
+            if let Some(open) = self.args {
+                let at_end = self.done && self.tokens.clone().next().is_none();
+                return (at_end && open.kind == TokenKind::Lt).then(|| &self.name[..open.offset]);
+            }
+        }
+        None
+    }
+
+    /// Gets the next segment along with its byte offset in the name.
+    pub(crate) fn next_spanned(&mut self) -> Option<(usize, &'a str)> {
+        if self.done {
+            return None;
+        }
+
+        let token = self.tokens.next()?;
+        let segment = match token.kind {
+            TokenKind::Ident => token.text,
+            TokenKind::OpenBrace => {
+                let end = skip_group(&mut self.tokens);
+                &self.name[token.offset..end]
+            }
+            _ => {
+                self.done = true;
+                return None;
+            }
+        };
+
+        self.args = peek(&self.tokens)
+            .filter(|token| matches!(token.kind, TokenKind::Lt | TokenKind::OpenParen));
+        if self.args.is_some() {
+            self.tokens.next();
+            skip_group(&mut self.tokens);
+        }
+        self.done = !eat(&mut self.tokens, TokenKind::PathSep);
+
+        Some((token.offset, segment))
+    }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_spanned().map(|(_, segment)| segment)
+    }
+}
+
+impl<'a> core::iter::FusedIterator for Segments<'a> {}
+
+/// An iterator over the top-level generic arguments of the outermost path of a name.
+///
+/// Returned by [`ShortName::generic_args`].
+#[derive(Clone, Debug)]
+pub struct GenericArgs<'a>(List<'a>);
+
+impl<'a> GenericArgs<'a> {
+    pub(crate) fn new(name: &'a str) -> Self {
+        let mut segments = Segments::new(name);
+        while segments.next().is_some() {
+            if let Some(open) = segments.args.filter(|token| token.kind == TokenKind::Lt) {
+                return Self(List::new(name, open, TokenKind::Comma));
+            }
+        }
+        Self(List::empty(name))
+    }
+}
+
+impl<'a> Iterator for GenericArgs<'a> {
+    type Item = ShortName<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(ShortName)
+    }
+}
+
+impl<'a> core::iter::FusedIterator for GenericArgs<'a> {}
+
+/// An iterator over the elements of a tuple, or the arguments of a call-like name.
+///
+/// Returned by [`ShortName::split_tuple_elements`].
+#[derive(Clone, Debug)]
+pub struct TupleElements<'a>(List<'a>);
+
+impl<'a> TupleElements<'a> {
+    pub(crate) fn new(name: &'a str) -> Self {
+        let mut tokens = TypeNameTokens::new(name);
+        eat(&mut tokens, TokenKind::Space);
+        let open = match peek(&tokens) {
+            // A tuple such as `(a::B, c::D)`.
+            Some(token) if token.kind == TokenKind::OpenParen => Some(token),
+            // A path ending in parenthesized arguments such as `Pipe(a::b, c::d)`.
+            Some(_) => {
+                let mut segments = Segments::new(name);
+                while segments.next().is_some() && !segments.done {}
+                segments
+                    .args
+                    .filter(|token| token.kind == TokenKind::OpenParen)
+            }
+            None => None,
+        };
+
+        // The group must span the rest of the name, unlike in `(A, B)::default`.
+        match open.filter(|open| spans_rest(name, open)) {
+            Some(open) => Self(List::new(name, open, TokenKind::Comma)),
+            None => Self(List::empty(name)),
+        }
+    }
+}
+
+impl<'a> Iterator for TupleElements<'a> {
+    type Item = ShortName<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(ShortName)
+    }
+}
+
+impl<'a> core::iter::FusedIterator for TupleElements<'a> {}
+
+/// A generic argument, or a part of an array type, classified by what it is.
+///
+/// Returned by [`ShortName::typed_generic_args`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum GenericArgument<'a> {
+    /// A type, such as `u32` in `Vec<u32>` or `i32` in `[i32; 3]`.
+    Type(ShortName<'a>),
+    /// A const value, such as `16` in `ArrayVec<u8, 16>` or `3` in `[i32; 3]`.
+    Const(&'a str),
+    /// A lifetime, such as `'static` in `Res<'static, T>`.
+    Lifetime(&'a str),
+    /// An associated type binding, such as `Item = u32` in `Iterator<Item = u32>`.
+    Binding(&'a str, ShortName<'a>),
+}
+
+impl<'a> GenericArgument<'a> {
+    fn classify(arg: &'a str) -> Self {
+        let mut tokens = TypeNameTokens::new(arg);
+        let Some(first) = tokens.next() else {
+            return Self::Type(ShortName(arg));
+        };
+        match first.kind {
+            TokenKind::Lifetime => Self::Lifetime(arg),
+            TokenKind::Literal => Self::Const(arg),
+            TokenKind::Ident if matches!(first.text, "true" | "false") => Self::Const(arg),
+            TokenKind::Ident => {
+                eat(&mut tokens, TokenKind::Space);
+                match tokens.next() {
+                    Some(eq) if eq.kind == TokenKind::Eq => {
+                        let ty = arg[eq.offset + eq.text.len()..].trim_start();
+                        Self::Binding(first.text, ShortName(ty))
+                    }
+                    _ => Self::Type(ShortName(arg)),
+                }
+            }
+            _ => Self::Type(ShortName(arg)),
+        }
+    }
+}
+
+/// An iterator over the generic arguments of a name, or the element type and
+/// length of an array, classified by what they are.
+///
+/// Returned by [`ShortName::typed_generic_args`].
+#[derive(Clone, Debug)]
+pub struct TypedGenericArgs<'a>(List<'a>);
+
+impl<'a> TypedGenericArgs<'a> {
+    pub(crate) fn new(name: &'a str) -> Self {
+        let mut tokens = TypeNameTokens::new(name);
+        eat(&mut tokens, TokenKind::Space);
+        match tokens.next() {
+            Some(open) if open.kind == TokenKind::OpenBracket && spans_rest(name, &open) => {
+                Self(List::new(name, open, TokenKind::Semicolon))
+            }
+            _ => Self(GenericArgs::new(name).0),
+        }
+    }
+}
+
+impl<'a> Iterator for TypedGenericArgs<'a> {
+    type Item = GenericArgument<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(GenericArgument::classify)
+    }
+}
+
+impl<'a> core::iter::FusedIterator for TypedGenericArgs<'a> {}
+
+/// An iterator over the bounds of a trait object or `impl Trait` type.
+///
+/// Returned by [`ShortName::bounds`].
+#[derive(Clone, Debug)]
+pub struct Bounds<'a>(List<'a>);
+
+impl<'a> Bounds<'a> {
+    pub(crate) fn new(name: &'a str) -> Self {
+        let mut tokens = TypeNameTokens::new(name);
+        eat(&mut tokens, TokenKind::Space);
+        match tokens.next() {
+            Some(token)
+                if token.kind == TokenKind::Ident && matches!(token.text, "dyn" | "impl") =>
+            {
+                Self(List::new(name, token, TokenKind::Plus))
+            }
+            _ => Self(List::empty(name)),
+        }
+    }
+}
+
+impl<'a> Iterator for Bounds<'a> {
+    type Item = ShortName<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(ShortName)
+    }
+}
+
+impl<'a> core::iter::FusedIterator for Bounds<'a> {}
+
+/// An iterator over the lifetimes anywhere in a name.
+///
+/// Returned by [`ShortName::lifetimes`].
+#[derive(Clone, Debug)]
+pub struct Lifetimes<'a>(TypeNameTokens<'a>);
+
+impl<'a> Lifetimes<'a> {
+    pub(crate) fn new(name: &'a str) -> Self {
+        Self(TypeNameTokens::new(name))
+    }
+}
+
+impl<'a> Iterator for Lifetimes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .find(|token| token.kind == TokenKind::Lifetime)
+            .map(|token| token.text)
+    }
+}
+
+impl<'a> core::iter::FusedIterator for Lifetimes<'a> {}
+
+/// An iterator over the associated type bindings anywhere in a name.
+///
+/// Returned by [`ShortName::bindings`].
+#[derive(Clone, Debug)]
+pub struct Bindings<'a> {
+    name: &'a str,
+    tokens: TypeNameTokens<'a>,
+}
+
+impl<'a> Bindings<'a> {
+    pub(crate) fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            tokens: TypeNameTokens::new(name),
+        }
+    }
+}
+
+impl<'a> Iterator for Bindings<'a> {
+    type Item = (&'a str, ShortName<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut previous: Option<Token<'a>> = None;
+        for token in self.tokens.by_ref() {
+            match token.kind {
+                TokenKind::Space => continue,
+                TokenKind::Eq => {
+                    if let Some(Token {
+                        kind: TokenKind::Ident,
+                        text: binding,
+                        ..
+                    }) = previous
+                    {
+                        let ty = List::new(self.name, token, TokenKind::Comma).next()?;
+                        return Some((binding, ShortName(ty)));
+                    }
+                }
+                _ => {}
+            }
+            previous = Some(token);
+        }
+        None
+    }
+}
+
+impl<'a> core::iter::FusedIterator for Bindings<'a> {}
+
+/// Splits the contents of a bracketed group at top-level separators.
+#[derive(Clone, Debug)]
+struct List<'a> {
+    name: &'a str,
+    tokens: TypeNameTokens<'a>,
+    separator: TokenKind,
+    done: bool,
+}
+
+impl<'a> List<'a> {
+    /// Creates a list of the items following the `open` token.
+    fn new(name: &'a str, open: Token<'a>, separator: TokenKind) -> Self {
+        let mut tokens = TypeNameTokens::new(name);
+        tokens.find(|token| token.offset == open.offset);
+        Self {
+            name,
+            tokens,
+            separator,
+            done: false,
+        }
+    }
+
+    fn empty(name: &'a str) -> Self {
+        Self {
+            name,
+            tokens: TypeNameTokens::new(""),
+            separator: TokenKind::Comma,
+            done: true,
+        }
+    }
+}
+
+impl<'a> Iterator for List<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        eat(&mut self.tokens, TokenKind::Space);
+
+        let start = peek(&self.tokens)?.offset;
+        let mut end = start;
+        let mut depth = 0_usize;
+        for token in self.tokens.by_ref() {
+            if is_open(token.kind) {
+                depth += 1;
+            } else if is_close(token.kind) {
+                if depth == 0 {
+                    self.done = true;
+                    break;
+                }
+                depth -= 1;
+            } else if token.kind == self.separator && depth == 0 {
+                break;
+            }
+            end = token.offset + token.text.len();
+        }
+
+        let item = self.name[start..end].trim_end();
+        if item.is_empty() {
+            // A trailing separator, as in `(u32,)`, or an empty group.
+            self.done = true;
+            return None;
+        }
+        Some(item)
+    }
+}
+
+/// Keywords which start a type that is not a path, such as `dyn Trait`.
+fn is_type_keyword(ident: &str) -> bool {
+    matches!(ident, "dyn" | "impl" | "fn" | "unsafe" | "extern" | "for")
+}
+
+fn is_open(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Lt | TokenKind::OpenParen | TokenKind::OpenBracket | TokenKind::OpenBrace
+    )
+}
+
+fn is_close(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Gt | TokenKind::CloseParen | TokenKind::CloseBracket | TokenKind::CloseBrace
+    )
+}
+
+fn peek<'a>(tokens: &TypeNameTokens<'a>) -> Option<Token<'a>> {
+    tokens.clone().next()
+}
+
+fn eat(tokens: &mut TypeNameTokens<'_>, kind: TokenKind) -> bool {
+    let matches = matches!(peek(tokens), Some(token) if token.kind == kind);
+    if matches {
+        tokens.next();
+    }
+    matches
+}
+
+/// Whether the bracketed group opened by `open` ends at the end of `name`.
+fn spans_rest(name: &str, open: &Token<'_>) -> bool {
+    let mut tokens = TypeNameTokens::new(&name[open.offset + 1..]);
+    skip_group(&mut tokens);
+    eat(&mut tokens, TokenKind::Space);
+    tokens.next().is_none()
+}
+
+/// Skips to the end of a bracketed group whose opening token has already been consumed,
+/// returning the byte offset just past its closing token.
+pub(crate) fn skip_group(tokens: &mut TypeNameTokens<'_>) -> usize {
+    let mut depth = 1_usize;
+    let mut end = 0;
+    for token in tokens.by_ref() {
+        end = token.offset + token.text.len();
+        if is_open(token.kind) {
+            depth += 1;
+        } else if is_close(token.kind) {
+            depth -= 1;
+            if depth == 0 {
+                break;
+            }
+        }
+    }
+    end
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod iter_tests {
+    use crate::ShortName;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn chars() {
+        let chars = |name| ShortName(name).chars().collect::<alloc::string::String>();
+
+        assert_eq!(chars("a::B<c::D, e::F>"), "B<D, F>");
+        assert_eq!(chars("a::Größe"), "Größe");
+        assert_eq!(chars(""), "");
+        assert_eq!(chars("a::"), "");
+
+        let mut chars = ShortName("a::B").chars();
+        assert_eq!(chars.next(), Some('B'));
+        assert_eq!(chars.next(), None);
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn segments() {
+        let segments = |name| ShortName(name).segments().collect::<Vec<_>>();
+
+        assert_eq!(segments("test_system"), ["test_system"]);
+        assert_eq!(
+            segments("bevy_render::camera::camera::extract_cameras<bevy_render::camera::bundle::Camera3d>"),
+            ["bevy_render", "camera", "camera", "extract_cameras"]
+        );
+        assert_eq!(
+            segments("bevy_asset::assets::Assets<bevy_scene::DynamicScene>::asset_event_system"),
+            ["bevy_asset", "assets", "Assets", "asset_event_system"]
+        );
+        assert_eq!(
+            segments("core::option::Option::Some(2)"),
+            ["core", "option", "Option", "Some"]
+        );
+        assert_eq!(
+            segments("my_crate::system::{{closure}}"),
+            ["my_crate", "system", "{{closure}}"]
+        );
+        assert_eq!(
+            segments("<T as core::default::Default>::default"),
+            ["default"]
+        );
+        assert_eq!(segments("(String, String)::default"), ["default"]);
+        assert_eq!(segments("(String, String)"), [] as [&str; 0]);
+        assert_eq!(segments("&alloc::string::String"), [] as [&str; 0]);
+        assert_eq!(segments("dyn core::any::Any"), [] as [&str; 0]);
+        assert_eq!(segments(""), [] as [&str; 0]);
+    }
+
+    #[test]
+    fn ident() {
+        let ident = |name| ShortName(name).ident();
+
+        assert_eq!(
+            ident("bevy_asset::Assets<bevy_image::Image>"),
+            Some("Assets")
+        );
+        assert_eq!(
+            ident("bevy_asset::Assets<Image>::track_assets"),
+            Some("track_assets")
+        );
+        assert_eq!(ident("core::option::Option::Some(2)"), Some("Some"));
+        assert_eq!(ident("u32"), Some("u32"));
+        assert_eq!(ident("(u32, u8)"), None);
+    }
+
+    #[test]
+    fn crate_name() {
+        let crate_name = |name| ShortName(name).crate_name();
+
+        assert_eq!(
+            crate_name("bevy_render::camera::Camera"),
+            Some("bevy_render")
+        );
+        assert_eq!(
+            crate_name("core::option::Option<alloc::string::String>"),
+            Some("core")
+        );
+        assert_eq!(crate_name("a::B<C>::d"), Some("a"));
+        assert_eq!(crate_name("u32"), None);
+        assert_eq!(crate_name("Vec<a::B>"), None);
+        assert_eq!(crate_name("<a::B as c::D>::e::f"), None);
+        assert_eq!(crate_name("&a::B"), None);
+    }
+
+    #[test]
+    fn module_path() {
+        let module_path = |name| ShortName(name).module_path();
+
+        assert_eq!(module_path("foo::bar::Baz<T>"), Some("foo::bar"));
+        assert_eq!(module_path("foo::bar::baz"), Some("foo::bar"));
+        assert_eq!(
+            module_path("bevy_render::RenderSet::Prepare"),
+            Some("bevy_render")
+        );
+        assert_eq!(
+            module_path("bevy_asset::assets::Assets<bevy_scene::DynamicScene>::asset_event_system"),
+            Some("bevy_asset::assets")
+        );
+        assert_eq!(
+            module_path("my_crate::system::{{closure}}"),
+            Some("my_crate::system")
+        );
+        assert_eq!(module_path("Baz"), None);
+        assert_eq!(module_path("Vec<a::B>"), None);
+        assert_eq!(module_path("<a::B as c::D>::e"), None);
+    }
+
+    #[test]
+    fn common_ancestor_module() {
+        let common = |a, b| ShortName(a).common_ancestor_module(ShortName(b));
+
+        assert_eq!(common("a::b::c::D", "a::b::E"), Some("a::b"));
+        assert_eq!(common("a::b::c::D", "a::b::c::E<f::G>"), Some("a::b::c"));
+        assert_eq!(common("a::b::D", "a::bc::E"), Some("a"));
+        assert_eq!(common("a::b::D", "a::b::D"), Some("a::b"));
+        assert_eq!(common("a::b::D", "b::b::D"), None);
+        assert_eq!(common("a::b::D", "E"), None);
+        assert_eq!(common("<a::B as a::C>::d", "a::e"), None);
+    }
+
+    #[test]
+    fn parent() {
+        let parent = |name| ShortName(name).parent().map(|parent| parent.original());
+
+        assert_eq!(parent("a::b::C<d::E>"), Some("a::b"));
+        assert_eq!(parent("a::b"), Some("a"));
+        assert_eq!(parent("a::B<C>::d"), Some("a::B<C>"));
+        assert_eq!(parent("a::RenderSet::Prepare"), Some("a::RenderSet"));
+        assert_eq!(parent("a::system::{{closure}}"), Some("a::system"));
+        assert_eq!(parent("<a::B as c::D>::e"), Some("<a::B as c::D>"));
+        assert_eq!(parent("<a::B as c::D>"), None);
+        assert_eq!(parent("a"), None);
+        assert_eq!(parent("Vec<a::B>"), None);
+        assert_eq!(parent("&a::B"), None);
+        assert_eq!(parent(""), None);
+    }
+
+    #[test]
+    fn nesting_depth() {
+        let nesting_depth = |name| ShortName(name).nesting_depth();
+
+        assert_eq!(nesting_depth("test_system"), 0);
+        assert_eq!(nesting_depth("a::B<C>"), 1);
+        assert_eq!(nesting_depth("a::B<C<D>, (E, [F; 3])>"), 3);
+        assert_eq!(nesting_depth("fn(u8) -> u8"), 1);
+        assert_eq!(nesting_depth("a::B<C>::d<E>"), 1);
+        assert_eq!(nesting_depth("unbalanced>>"), 0);
+    }
+
+    #[test]
+    fn strip_wrappers() {
+        let strip = |name, wrappers| ShortName(name).strip_wrappers(wrappers).original();
+        let wrappers = &["alloc::sync::Arc", "Mutex", "alloc::boxed::Box"];
+
+        assert_eq!(
+            strip(
+                "alloc::sync::Arc<std::sync::mutex::Mutex<game::GameState>>",
+                wrappers
+            ),
+            "game::GameState"
+        );
+        assert_eq!(
+            strip("alloc::boxed::Box<dyn core::any::Any>", wrappers),
+            "dyn core::any::Any"
+        );
+        assert_eq!(
+            strip("alloc::sync::Arc<a::B>::new", wrappers),
+            "alloc::sync::Arc<a::B>::new"
+        );
+        assert_eq!(strip("my_crate::Arc<u32>", wrappers), "my_crate::Arc<u32>");
+        assert_eq!(
+            strip("alloc::vec::Vec<u8>", wrappers),
+            "alloc::vec::Vec<u8>"
+        );
+        assert_eq!(strip("alloc::sync::Arc<u8>", &[]), "alloc::sync::Arc<u8>");
+    }
+
+    #[test]
+    fn generic_args() {
+        let generic_args = |name| {
+            ShortName(name)
+                .generic_args()
+                .map(|arg| arg.original())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            generic_args("std::collections::HashMap<u32, alloc::vec::Vec<(u8, u16)>>"),
+            ["u32", "alloc::vec::Vec<(u8, u16)>"]
+        );
+        assert_eq!(
+            generic_args("a::Foo<(B, C), [D; 3], fn(E, F) -> G, 'a>"),
+            ["(B, C)", "[D; 3]", "fn(E, F) -> G", "'a"]
+        );
+        assert_eq!(
+            generic_args("bevy_asset::Assets<bevy_scene::DynamicScene>::asset_event_system"),
+            ["bevy_scene::DynamicScene"]
+        );
+        assert_eq!(generic_args("a::Foo<>"), [] as [&str; 0]);
+        assert_eq!(generic_args("a::Foo"), [] as [&str; 0]);
+        assert_eq!(
+            generic_args("core::option::Option::Some(2)"),
+            [] as [&str; 0]
+        );
+        assert_eq!(generic_args("(A<B>, C)"), [] as [&str; 0]);
+    }
+
+    #[test]
+    fn typed_generic_args() {
+        use crate::GenericArgument;
+        let typed_generic_args = |name| {
+            ShortName(name)
+                .typed_generic_args()
+                .map(|arg| match arg {
+                    GenericArgument::Type(ty) => ("type", ty.original()),
+                    GenericArgument::Const(value) => ("const", value),
+                    GenericArgument::Lifetime(lifetime) => ("lifetime", lifetime),
+                    GenericArgument::Binding(_, ty) => ("binding", ty.original()),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            typed_generic_args("arrayvec::ArrayVec<alloc::string::String, 16>"),
+            [("type", "alloc::string::String"), ("const", "16")]
+        );
+        assert_eq!(
+            typed_generic_args("[(i32, u8); 3]"),
+            [("type", "(i32, u8)"), ("const", "3")]
+        );
+        assert_eq!(typed_generic_args("[u8]"), [("type", "u8")]);
+        assert_eq!(
+            typed_generic_args("a::B<'static, -1, 'x', true, \"s\">"),
+            [
+                ("lifetime", "'static"),
+                ("const", "-1"),
+                ("const", "'x'"),
+                ("const", "true"),
+                ("const", "\"s\""),
+            ]
+        );
+        assert_eq!(
+            typed_generic_args("a::Iterator<Item = b::C<D>>"),
+            [("binding", "b::C<D>")]
+        );
+        assert_eq!(typed_generic_args("dyn a::Iterator<Item = u8>"), []);
+        assert_eq!(typed_generic_args("[u8; 3]::len"), []);
+    }
+
+    #[test]
+    fn bounds() {
+        let bounds = |name| {
+            ShortName(name)
+                .bounds()
+                .map(|bound| bound.original())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            bounds("dyn foo::Trait + core::marker::Send + core::marker::Sync"),
+            ["foo::Trait", "core::marker::Send", "core::marker::Sync"]
+        );
+        assert_eq!(
+            bounds("impl core::ops::function::Fn(a::B<C>) -> D + 'static"),
+            ["core::ops::function::Fn(a::B<C>) -> D", "'static"]
+        );
+        assert_eq!(
+            bounds("dyn for<'a> a::Visit<'a, Item = (u8, u16)> + Send"),
+            ["for<'a> a::Visit<'a, Item = (u8, u16)>", "Send"]
+        );
+        assert_eq!(bounds("dyn core::any::Any"), ["core::any::Any"]);
+        assert_eq!(bounds("alloc::boxed::Box<dyn a::B + C>"), [] as [&str; 0]);
+        assert_eq!(bounds("dynamic::Thing"), [] as [&str; 0]);
+    }
+
+    #[test]
+    fn lifetimes() {
+        let lifetimes = |name| ShortName(name).lifetimes().collect::<Vec<_>>();
+
+        assert_eq!(
+            lifetimes("&'a a::B<'static, &'_ C, dyn D + 'a>"),
+            ["'a", "'static", "'_", "'a"]
+        );
+        assert_eq!(
+            lifetimes("for<'de> fn(&'de str, char, [char; 3]) -> a::B<'de>"),
+            ["'de", "'de", "'de"]
+        );
+        assert_eq!(lifetimes("a::C<'x', '\\''>"), [] as [&str; 0]);
+        assert_eq!(lifetimes("alloc::string::String"), [] as [&str; 0]);
+    }
+
+    #[test]
+    fn bindings() {
+        let bindings = |name| {
+            ShortName(name)
+                .bindings()
+                .map(|(binding, ty)| (binding, ty.original()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            bindings("dyn core::iter::traits::iterator::Iterator<Item = foo::Bar>"),
+            [("Item", "foo::Bar")]
+        );
+        assert_eq!(
+            bindings("alloc::boxed::Box<dyn a::Future<Output = (u8, b::C<D>)> + Send>"),
+            [("Output", "(u8, b::C<D>)")]
+        );
+        assert_eq!(
+            bindings("dyn a::Stream<Item = b::C<dyn d::Future<Output = u8> + E>, Error = F>"),
+            [
+                ("Item", "b::C<dyn d::Future<Output = u8> + E>"),
+                ("Output", "u8"),
+                ("Error", "F"),
+            ]
+        );
+        assert_eq!(bindings("a::B<c::D>"), []);
+    }
+
+    #[test]
+    fn split_tuple_elements() {
+        let elements = |name| {
+            ShortName(name)
+                .split_tuple_elements()
+                .map(|element| element.original())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            elements("(bevy_ecs::Res<a::B>, (c::D, [e::F; 2]))"),
+            ["bevy_ecs::Res<a::B>", "(c::D, [e::F; 2])"]
+        );
+        assert_eq!(
+            elements("bevy_ecs::system::Pipe(a::b, c::d<E, F>)"),
+            ["a::b", "c::d<E, F>"]
+        );
+        assert_eq!(elements("(u32,)"), ["u32"]);
+        assert_eq!(elements("()"), [] as [&str; 0]);
+        assert_eq!(elements("(String, String)::default"), [] as [&str; 0]);
+        assert_eq!(elements("a::Fn(u8)::call"), [] as [&str; 0]);
+        assert_eq!(elements("alloc::vec::Vec<(u8, u16)>"), [] as [&str; 0]);
+        assert_eq!(elements("fn(u8, u16)"), [] as [&str; 0]);
+        assert_eq!(elements("(u8, u16"), ["u8", "u16"]);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{ShortName, Token, TokenKind, TypeNameTokens};
+
+/// What sort of item a [`ShortName`] refers to, as returned by [`ShortName::kind`].
+///
+/// This is determined from the structure of the name alone, so it is a best guess.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NameKind {
+    /// A type, such as `alloc::vec::Vec<u8>`, `(u8, u16)` or `&str`.
+    Type,
+    /// A function, such as `my_game::move_player` or `alloc::vec::Vec<u8>::new`.
+    Function,
+    /// A closure, such as `my_game::setup::{{closure}}`.
+    Closure,
+    /// An async block or the body of an async function, such as `my_game::load::{async_block#0}`.
+    AsyncBlock,
+    /// The name is empty or not a recognizable path.
+    Unknown,
+}
+
+/// A path segment generated by the compiler rather than written in the source,
+/// such as `{impl#0}`, `{closure#1}` or `{{closure}}`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Marker<'a> {
+    /// What the marker stands for.
+    pub kind: MarkerKind,
+    /// The name inside the braces, such as `closure` in `{closure#1}`.
+    pub name: &'a str,
+    /// The index distinguishing markers of the same kind within the same parent,
+    /// such as `1` in `{closure#1}`, if present.
+    pub index: Option<u32>,
+}
+
+impl<'a> Marker<'a> {
+    /// Recognizes a marker segment, such as `{impl#0}`.
+    ///
+    /// Returns [`None`] if `segment` is not wrapped in braces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{Marker, MarkerKind};
+    /// let marker = Marker::parse("{closure#1}").unwrap();
+    /// assert_eq!(marker.kind, MarkerKind::Closure);
+    /// assert_eq!(marker.index, Some(1));
+    ///
+    /// assert_eq!(Marker::parse("{{closure}}").unwrap().index, None);
+    /// assert_eq!(Marker::parse("closure"), None);
+    /// ```
+    pub fn parse(segment: &'a str) -> Option<Self> {
+        let mut inner = segment.strip_prefix('{')?.strip_suffix('}')?;
+        // Older compilers write markers in double braces, as in `{{closure}}`.
+        if let Some(unwrapped) = inner.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            inner = unwrapped;
+        }
+
+        let (name, index) = match inner.split_once('#') {
+            Some((name, index)) => (name, index.parse().ok()),
+            None => (inner, None),
+        };
+        let kind = match name {
+            "impl" => MarkerKind::Impl,
+            "closure" => MarkerKind::Closure,
+            "constant" => MarkerKind::Constant,
+            "async_block" | "async_fn_body" => MarkerKind::AsyncBlock,
+            "opaque" => MarkerKind::Opaque,
+            _ => MarkerKind::Other,
+        };
+        Some(Self { kind, name, index })
+    }
+}
+
+/// What a [`Marker`] stands for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MarkerKind {
+    /// An `impl` block, as in `{impl#0}`.
+    Impl,
+    /// A closure, as in `{closure#0}` or `{{closure}}`.
+    Closure,
+    /// A constant expression, such as an array length, as in `{constant#0}`.
+    Constant,
+    /// An async block or the body of an async function, as in `{async_block#0}`.
+    AsyncBlock,
+    /// An `impl Trait` type, as in `{opaque#0}`.
+    Opaque,
+    /// Any other marker.
+    Other,
+}
+
+pub(crate) fn classify(name: ShortName<'_>) -> NameKind {
+    let Some(ident) = name.ident() else {
+        // Tuples, references, trait objects and other types which are not paths.
+        let first = TypeNameTokens::new(name.0).find(|token| token.kind != TokenKind::Space);
+        return match first {
+            Some(Token {
+                kind:
+                    TokenKind::OpenParen
+                    | TokenKind::OpenBracket
+                    | TokenKind::And
+                    | TokenKind::Star
+                    | TokenKind::Bang,
+                ..
+            }) => NameKind::Type,
+            Some(Token {
+                kind: TokenKind::Ident,
+                text: "dyn" | "impl" | "fn" | "unsafe" | "extern" | "for",
+                ..
+            }) => NameKind::Type,
+            _ => NameKind::Unknown,
+        };
+    };
+
+    if let Some(marker) = Marker::parse(ident) {
+        match marker.kind {
+            MarkerKind::AsyncBlock => NameKind::AsyncBlock,
+            MarkerKind::Closure => NameKind::Closure,
+            _ => NameKind::Unknown,
+        }
+    } else if ident.starts_with(char::is_uppercase) || is_primitive(ident) {
+        NameKind::Type
+    } else if ident.starts_with(|c: char| c.is_lowercase() || c == '_') {
+        NameKind::Function
+    } else {
+        NameKind::Unknown
+    }
+}
+
+/// The names of Rust's primitive types, as they appear in type names.
+const PRIMITIVES: &[&str] = &[
+    "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+    "i128", "isize", "f16", "f32", "f64", "f128", "()", "!",
+];
+
+/// The crates making up the standard library.
+const STD_CRATES: &[&str] = &["core", "alloc", "std"];
+
+/// Whether `name` is one of Rust's primitive types.
+pub(crate) fn is_primitive(name: &str) -> bool {
+    PRIMITIVES.contains(&name)
+}
+
+/// Whether `crate_name` is one of the crates making up the standard library.
+pub(crate) fn is_std_crate(crate_name: &str) -> bool {
+    STD_CRATES.contains(&crate_name)
+}
+
+#[cfg(test)]
+mod kind_tests {
+    use super::NameKind;
+    use crate::ShortName;
+
+    #[test]
+    fn kind() {
+        let kind = |name| ShortName(name).kind();
+
+        assert_eq!(kind("alloc::vec::Vec<u8>"), NameKind::Type);
+        assert_eq!(kind("u32"), NameKind::Type);
+        assert_eq!(kind("bevy_render::RenderSet::Prepare"), NameKind::Type);
+        assert_eq!(kind("(u8, u16)"), NameKind::Type);
+        assert_eq!(kind("&str"), NameKind::Type);
+        assert_eq!(kind("dyn core::any::Any"), NameKind::Type);
+        assert_eq!(kind("fn(u8) -> u8"), NameKind::Type);
+        assert_eq!(kind("my_game::move_player"), NameKind::Function);
+        assert_eq!(kind("alloc::vec::Vec<u8>::new"), NameKind::Function);
+        assert_eq!(kind("my_game::setup::{{closure}}"), NameKind::Closure);
+        assert_eq!(kind("my_game::setup::{closure#0}"), NameKind::Closure);
+        assert_eq!(kind("my_game::load::{async_block#0}"), NameKind::AsyncBlock);
+        assert_eq!(
+            kind("my_game::load::{async_fn_body#0}"),
+            NameKind::AsyncBlock
+        );
+        assert_eq!(kind(""), NameKind::Unknown);
+        assert_eq!(kind("42"), NameKind::Unknown);
+    }
+
+    #[test]
+    fn is_primitive() {
+        let is_primitive = |name| ShortName(name).is_primitive();
+
+        assert!(is_primitive("u32"));
+        assert!(is_primitive("str"));
+        assert!(is_primitive("()"));
+        assert!(is_primitive(" bool "));
+        assert!(!is_primitive("&str"));
+        assert!(!is_primitive("my_game::u32"));
+        assert!(!is_primitive("alloc::string::String"));
+    }
+
+    #[test]
+    fn is_std_type() {
+        let is_std_type = |name| ShortName(name).is_std_type();
+
+        assert!(is_std_type("alloc::vec::Vec<my_game::Player>"));
+        assert!(is_std_type("core::option::Option::Some(2)"));
+        assert!(is_std_type(
+            "std::collections::hash::map::HashMap<u32, u32>"
+        ));
+        assert!(!is_std_type("my_game::Player"));
+        assert!(!is_std_type("u32"));
+        assert!(!is_std_type("core_extensions::Thing"));
+        assert!(!is_std_type("&alloc::string::String"));
+    }
+}
@@ -0,0 +1,134 @@
+use core::fmt::{self, Write};
+
+/// Writes `text`, a rendered type name, as words for people to read, such as
+/// `Extract Cameras (Camera 3d)` for `extract_cameras<Camera3d>`.
+///
+/// Identifiers are split into capitalized words at underscores and changes of case,
+/// paths are joined with spaces, and generic arguments are put in parentheses.
+/// Other punctuation is written as is.
+///
+/// Words which are one of `acronyms`, ignoring case, are written as in `acronyms`.
+pub(crate) fn write_label(w: &mut dyn Write, text: &str, acronyms: &[&str]) -> fmt::Result {
+    let mut rest = text;
+    // Whether a word was just written, so the next one needs a space before it.
+    let mut separate = false;
+    let mut previous = ' ';
+    while let Some(c) = rest.chars().next() {
+        if c.is_alphanumeric() {
+            let end = rest
+                .find(|c: char| !c.is_alphanumeric())
+                .unwrap_or(rest.len());
+            for (word, plural) in words(&rest[..end], acronyms) {
+                if separate {
+                    w.write_char(' ')?;
+                }
+                capitalize(w, word)?;
+                w.write_str(plural)?;
+                separate = true;
+            }
+            previous = 'a';
+            rest = &rest[end..];
+            continue;
+        }
+
+        match c {
+            '_' | ':' => {}
+            '<' => {
+                w.write_str(" (")?;
+                separate = false;
+            }
+            '>' if previous != '-' => {
+                w.write_char(')')?;
+                separate = true;
+            }
+            _ => {
+                w.write_char(c)?;
+                separate = false;
+            }
+        }
+        previous = c;
+        rest = &rest[c.len_utf8()..];
+    }
+    Ok(())
+}
+
+/// Splits an identifier without underscores into words where its case changes.
+///
+/// A run of uppercase letters is kept together as an acronym, except for a last
+/// letter which starts a capitalized word, and a number is only split from the
+/// letters before it when more letters follow, as in `Camera3d`.
+///
+/// A word is instead one of `acronyms` when the rest of the identifier starts with
+/// it, ignoring case, and does not continue it with lowercase letters other than a
+/// plural `s`, which is returned after the acronym, or with more of a run of
+/// uppercase letters.
+fn words<'t>(ident: &'t str, acronyms: &'t [&'t str]) -> impl Iterator<Item = (&'t str, &'t str)> {
+    let mut rest = ident;
+    core::iter::from_fn(move || {
+        if let Some((acronym, plural)) = find_acronym(rest, acronyms) {
+            rest = &rest[acronym.len() + plural.len()..];
+            return Some((acronym, plural));
+        }
+
+        let mut chars = rest.char_indices().peekable();
+        let (_, mut previous) = chars.next()?;
+        let mut end = rest.len();
+        while let Some((i, c)) = chars.next() {
+            let next_is_lowercase = chars.peek().is_some_and(|&(_, next)| next.is_lowercase());
+            let boundary = (previous.is_lowercase() && c.is_uppercase())
+                || (previous.is_uppercase() && c.is_uppercase() && next_is_lowercase)
+                || (previous.is_alphabetic()
+                    && c.is_numeric()
+                    && rest[i..]
+                        .trim_start_matches(char::is_numeric)
+                        .starts_with(char::is_alphabetic));
+            if boundary {
+                end = i;
+                break;
+            }
+            previous = c;
+        }
+        let (word, tail) = rest.split_at(end);
+        rest = tail;
+        Some((word, ""))
+    })
+}
+
+/// Finds the longest of `acronyms` which `ident` starts with as a word, along with
+/// the plural `s` following it, if any.
+fn find_acronym<'t>(ident: &str, acronyms: &'t [&'t str]) -> Option<(&'t str, &'t str)> {
+    acronyms
+        .iter()
+        .filter(|acronym| !acronym.is_empty())
+        .filter_map(|&acronym| {
+            let start = ident
+                .get(..acronym.len())
+                .filter(|start| start.eq_ignore_ascii_case(acronym))?;
+            let rest = &ident[acronym.len()..];
+            // A run of uppercase letters which goes on past the acronym is a longer one.
+            let mut next = rest.chars();
+            if start.ends_with(char::is_uppercase)
+                && next.next().is_some_and(char::is_uppercase)
+                && !next.next().is_some_and(char::is_lowercase)
+            {
+                return None;
+            }
+            let plural = if rest.starts_with('s') && !rest[1..].starts_with(char::is_lowercase) {
+                "s"
+            } else {
+                ""
+            };
+            (!rest[plural.len()..].starts_with(char::is_lowercase)).then_some((acronym, plural))
+        })
+        .max_by_key(|(acronym, _)| acronym.len())
+}
+
+fn capitalize(w: &mut dyn Write, word: &str) -> fmt::Result {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+        for upper in first.to_uppercase() {
+            w.write_char(upper)?;
+        }
+    }
+    w.write_str(chars.as_str())
+}
@@ -0,0 +1,201 @@
+use core::fmt::{self, Write};
+
+/// How far each level of broken generic arguments is indented.
+const INDENT: usize = 4;
+
+/// How many levels of groups are broken onto lines of their own at most. Groups
+/// nested deeper are written on one line, so that breaking a deeply nested name
+/// takes time linear in its length rather than quadratic.
+pub(crate) const MAX_BREAK_DEPTH: usize = 16;
+
+/// Writes `text`, a rendered type name, breaking the generic arguments and tuple
+/// elements of groups which do not fit in `width` columns onto lines of their own.
+///
+/// Text without a group to break, such as a single long path, is written as is.
+/// Groups which were cut short, as when the name was truncated, are closed again.
+pub(crate) fn break_lines(w: &mut dyn Write, text: &str, width: usize) -> fmt::Result {
+    lines(w, text, 0, width)
+}
+
+fn lines(w: &mut dyn Write, text: &str, indent: usize, width: usize) -> fmt::Result {
+    if indent + text.chars().count() <= width || indent >= MAX_BREAK_DEPTH * INDENT {
+        return w.write_str(text);
+    }
+    let Some(open) = find_breakable(text) else {
+        return w.write_str(text);
+    };
+    let close = matching_close(text, open);
+
+    let inner = &text[open + 1..close.unwrap_or(text.len())];
+    if inner.trim().is_empty() {
+        return w.write_str(text);
+    }
+    w.write_str(&text[..=open])?;
+    let mut items = split_top_level(inner)
+        .filter(|item| !item.is_empty())
+        .peekable();
+    while let Some(item) = items.next() {
+        new_line(w, indent + INDENT)?;
+        lines(w, item, indent + INDENT, width)?;
+        // The last item of a group which was cut short is the one it was cut in.
+        if close.is_some() || items.peek().is_some() {
+            w.write_char(',')?;
+        }
+    }
+    new_line(w, indent)?;
+    let Some(close) = close else {
+        return w.write_char(if text[open..].starts_with('<') {
+            '>'
+        } else {
+            ')'
+        });
+    };
+    w.write_str(&text[close..=close])?;
+    lines(w, &text[close + 1..], indent, width)
+}
+
+/// Starts a new line indented by `indent` spaces.
+fn new_line(w: &mut dyn Write, indent: usize) -> fmt::Result {
+    w.write_char('\n')?;
+    (0..indent).try_for_each(|_| w.write_char(' '))
+}
+
+/// Writes `text`, a rendered type name, wrapping it at the commas directly inside
+/// its outermost groups so that lines stay within `width` columns where possible.
+///
+/// Each continuation line is aligned with the start of the group the comma is in,
+/// and items which are too long on their own are left to run over.
+pub(crate) fn wrap(w: &mut dyn Write, text: &str, width: usize) -> fmt::Result {
+    let mut column = 0;
+    let mut group_column = 0;
+    let mut depth = 0usize;
+    let mut previous = ' ';
+    for (index, piece) in split_at_depth(text, 1).enumerate() {
+        if index > 0 {
+            if column + 1 + piece.chars().count() > width {
+                new_line(w, group_column)?;
+                column = group_column;
+            } else {
+                w.write_char(' ')?;
+                column += 1;
+            }
+        }
+        for c in piece.chars() {
+            match c {
+                '>' if previous == '-' => {}
+                '<' | '(' | '[' => {
+                    depth += 1;
+                    if depth == 1 {
+                        group_column = column + 1;
+                    }
+                }
+                '>' | ')' | ']' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            previous = c;
+            column += 1;
+        }
+        w.write_str(piece)?;
+    }
+    Ok(())
+}
+
+/// Splits `text` after the commas nested exactly `depth` groups deep, trimming the
+/// space which follows them.
+fn split_at_depth(text: &str, depth: usize) -> impl Iterator<Item = &str> {
+    let mut rest = Some(text);
+    let mut current = 0usize;
+    let mut previous = ' ';
+    core::iter::from_fn(move || {
+        let text = rest?;
+        for (i, c) in text.char_indices() {
+            match c {
+                '>' if previous == '-' => {}
+                '<' | '(' | '[' => current += 1,
+                '>' | ')' | ']' => current = current.saturating_sub(1),
+                ',' if current == depth => {
+                    rest = Some(text[i + 1..].trim_start());
+                    return Some(&text[..=i]);
+                }
+                _ => {}
+            }
+            previous = c;
+        }
+        rest = None;
+        Some(text)
+    })
+}
+
+/// Finds the first opening bracket outside of any group whose contents can be
+/// written one per line, which excludes the brackets of arrays and slices.
+fn find_breakable(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut previous = ' ';
+    for (i, c) in text.char_indices() {
+        match c {
+            '<' | '(' if depth == 0 => return Some(i),
+            '<' | '(' | '[' => depth += 1,
+            '>' if previous == '-' => {}
+            '>' | ')' | ']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        previous = c;
+    }
+    None
+}
+
+/// Finds the bracket closing the group opened at `open`, if the text has one.
+fn matching_close(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut previous = ' ';
+    for (i, c) in text[open..].char_indices() {
+        match c {
+            '>' if previous == '-' => {}
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+        previous = c;
+    }
+    None
+}
+
+/// Splits the contents of a group at the commas outside of any nested group,
+/// trimming each item.
+fn split_top_level(inner: &str) -> TopLevelItems<'_> {
+    TopLevelItems { rest: Some(inner) }
+}
+
+struct TopLevelItems<'a> {
+    rest: Option<&'a str>,
+}
+
+impl<'a> Iterator for TopLevelItems<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest?;
+        let mut depth = 0usize;
+        let mut previous = ' ';
+        for (i, c) in rest.char_indices() {
+            match c {
+                '>' if previous == '-' => {}
+                '<' | '(' | '[' => depth += 1,
+                '>' | ')' | ']' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    self.rest = Some(&rest[i + 1..]);
+                    return Some(rest[..i].trim());
+                }
+                _ => {}
+            }
+            previous = c;
+        }
+        self.rest = None;
+        Some(rest.trim())
+    }
+}
@@ -1,5 +1,78 @@
-#![cfg_attr(not(feature = "alloc"), no_std)]
+#![no_std]
+#![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+mod analyzed;
+#[cfg(feature = "alloc")]
+mod builder;
+#[cfg(feature = "alloc")]
+mod cache;
+#[cfg(feature = "alloc")]
+mod config;
+mod display;
+mod error;
+mod flat;
+#[cfg(feature = "alloc")]
+mod interner;
+mod iter;
+mod kind;
+#[cfg(feature = "alloc")]
+mod label;
+#[cfg(feature = "alloc")]
+mod layout;
+#[cfg(feature = "alloc")]
+mod owned;
+#[cfg(feature = "alloc")]
+pub mod parse;
+#[cfg(feature = "std")]
+mod registry;
+#[cfg(feature = "alloc")]
+mod render;
 mod short_name;
+mod tokens;
+mod validate;
+mod visit;
 
+#[cfg(feature = "alloc")]
+pub use analyzed::AnalyzedName;
+#[cfg(feature = "alloc")]
+pub use builder::TypeNameBuilder;
+#[cfg(feature = "alloc")]
+pub use cache::ShortNameCache;
+#[cfg(feature = "std")]
+pub use config::{reset_default_config, set_default_config};
+#[cfg(feature = "alloc")]
+pub use config::{
+    ClosureStyle, EllipsisPosition, GenericArgsStyle, ShortNameConfig, ShortNameStyle, TupleStyle,
+};
+pub use display::{
+    Breakdown, Colored, DisplayWith, DotLabel, Html, Joined, Markdown, Piece, PieceKind,
+    WithContext, WithoutGenerics,
+};
+#[cfg(feature = "alloc")]
+pub use display::{Label, MapSegments, ShortDebug, ShortDisplay, WithConfig, Wrapped};
+pub use error::{CapacityError, Error, ParseError, ParseErrorKind};
+pub use flat::{parse_into, FlatTree, Node, NodeKind};
+#[cfg(feature = "alloc")]
+pub use interner::{InternedName, ShortNameInterner};
+pub use iter::{
+    Bindings, Bounds, Chars, GenericArgs, GenericArgument, Lifetimes, Segments, TupleElements,
+    TypedGenericArgs,
+};
+pub use kind::{Marker, MarkerKind, NameKind};
+#[cfg(feature = "alloc")]
+pub use owned::ShortNameOwned;
+#[cfg(feature = "std")]
+pub use registry::ShortNameRegistry;
+#[cfg(feature = "rayon")]
+pub use short_name::par_shorten_all;
+#[cfg(feature = "alloc")]
+pub use short_name::shorten_all;
 pub use short_name::ShortName;
+pub use tokens::{Token, TokenKind, TypeNameTokens};
+pub use visit::{walk, TypeNameVisitor};
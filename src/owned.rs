@@ -0,0 +1,178 @@
+use alloc::{borrow::Cow, boxed::Box, string::String};
+use core::{borrow::Borrow, fmt, ops::Deref};
+
+use crate::ShortName;
+
+/// An owned version of [`ShortName`], which keeps its own copy of the original
+/// name and so has no lifetime.
+///
+/// This is for storing names in long-lived structures, or names only built at
+/// runtime, such as those read from a file. The original name dereferences to a
+/// [`str`], while [`as_short_name`](Self::as_short_name) gets the [`ShortName`]
+/// for everything else.
+///
+/// Unlike [`ShortName`], owned names compare, order and hash by their original
+/// names, consistently with looking them up by a `&str` through [`Borrow`]. So
+/// `a::Player` and `b::Player` are different owned names, even though they are
+/// equal as [`ShortName`]s. To group owned names which shorten the same way,
+/// compare their [`as_short_name`](Self::as_short_name)s instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::{ShortName, ShortNameOwned};
+/// let name = ShortNameOwned::new(String::from("bevy_asset::assets::Assets<bevy_image::Image>"));
+/// assert_eq!(name.to_string(), "Assets<Image>");
+/// assert_eq!(&*name, "bevy_asset::assets::Assets<bevy_image::Image>");
+/// assert_eq!(name.as_short_name().ident(), Some("Assets"));
+///
+/// let owned: ShortNameOwned = ShortName("my_game::Player").into();
+/// assert_eq!(owned.to_string(), "Player");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShortNameOwned(Box<str>);
+
+impl ShortNameOwned {
+    /// Creates a name from the original, unshortened `name`.
+    pub fn new(name: impl Into<Box<str>>) -> Self {
+        Self(name.into())
+    }
+
+    /// Gets a [`ShortName`] borrowing this name.
+    pub fn as_short_name(&self) -> ShortName<'_> {
+        ShortName(&self.0)
+    }
+
+    /// Gets the original name before shortening.
+    pub fn original(&self) -> &str {
+        &self.0
+    }
+
+    /// Gets the original name before shortening, giving up ownership of it.
+    pub fn into_original(self) -> Box<str> {
+        self.0
+    }
+}
+
+impl<'a> From<ShortName<'a>> for ShortNameOwned {
+    fn from(name: ShortName<'a>) -> Self {
+        name.to_owned_name()
+    }
+}
+
+impl From<String> for ShortNameOwned {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+/// Reuses the allocation of an owned name, and copies a borrowed one.
+impl<'a> From<Cow<'a, str>> for ShortNameOwned {
+    fn from(name: Cow<'a, str>) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<Box<str>> for ShortNameOwned {
+    fn from(name: Box<str>) -> Self {
+        Self(name)
+    }
+}
+
+impl<'a> From<&'a str> for ShortNameOwned {
+    fn from(name: &'a str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl<'a> From<&'a ShortNameOwned> for ShortName<'a> {
+    fn from(name: &'a ShortNameOwned) -> Self {
+        name.as_short_name()
+    }
+}
+
+impl Deref for ShortNameOwned {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for ShortNameOwned {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for ShortNameOwned {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for ShortNameOwned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_short_name(), f)
+    }
+}
+
+impl fmt::Display for ShortNameOwned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_short_name(), f)
+    }
+}
+
+#[cfg(test)]
+mod owned_tests {
+    use super::ShortNameOwned;
+    use crate::ShortName;
+    use alloc::{
+        collections::BTreeSet,
+        format,
+        string::{String, ToString},
+    };
+
+    #[test]
+    fn displays_like_short_name() {
+        let name = ShortNameOwned::from(String::from("a::B<c::D>"));
+        assert_eq!(name.to_string(), "B<D>");
+        assert_eq!(format!("{name:?}"), "B<D>");
+        assert_eq!(format!("{name:#}"), "a::B<c::D>");
+        assert_eq!(format!("[{name:>6}]"), "[  B<D>]");
+        assert_eq!(name.original(), "a::B<c::D>");
+        assert_eq!(&*name.clone().into_original(), "a::B<c::D>");
+        assert_eq!(ShortName::from(&name).original(), "a::B<c::D>");
+    }
+
+    #[test]
+    fn looks_up_by_original_name() {
+        let mut names = BTreeSet::new();
+        names.insert(ShortNameOwned::from("a::B"));
+        names.insert(ShortName("c::D").to_owned_name());
+        assert!(names.contains("a::B"));
+        assert!(!names.contains("B"));
+        assert_eq!(ShortNameOwned::default().to_string(), "");
+
+        // Owned names are distinct by their original names, unlike `ShortName`s.
+        let (a, c) = (ShortNameOwned::from("a::B"), ShortNameOwned::from("c::B"));
+        assert_ne!(a, c);
+        assert!(a < c);
+        assert_eq!(a.as_short_name(), c.as_short_name());
+        assert_eq!(names.len(), 2);
+        names.insert(c);
+        assert_eq!(names.len(), 3);
+    }
+
+    #[test]
+    fn from_cow() {
+        use alloc::borrow::Cow;
+
+        let borrowed: Cow<'_, str> = Cow::Borrowed("a::B");
+        let owned: Cow<'_, str> = Cow::Owned(String::from("c::D"));
+        assert_eq!(ShortName::from(&borrowed).to_string(), "B");
+        assert_eq!(ShortName::from(&owned).to_string(), "D");
+        assert_eq!(ShortNameOwned::from(borrowed).to_string(), "B");
+        assert_eq!(ShortNameOwned::from(owned).to_string(), "D");
+    }
+}
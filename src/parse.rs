@@ -0,0 +1,1014 @@
+//! Parsing of type names into a structured representation.
+//!
+//! Where [`ShortName`](crate::ShortName) works directly on the characters of a
+//! name, [`parse`] builds a [`TypeExpr`] tree that can be inspected and
+//! manipulated. The [`Display`](core::fmt::Display) implementations of the tree
+//! render the full name again, while [`TypeExpr::shortened`] renders it with
+//! module paths removed.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use disqualified::parse::{parse, TypeExpr};
+//! let ty = parse("alloc::vec::Vec<core::option::Option<u32>>").unwrap();
+//! let TypeExpr::Path(path) = &ty else { unreachable!() };
+//! assert_eq!(path.segments.len(), 3);
+//! assert_eq!(path.segments[2].ident, "Vec");
+//! assert_eq!(ty.to_string(), "alloc::vec::Vec<core::option::Option<u32>>");
+//! ```
+
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::fmt;
+
+use crate::{
+    render::Renderer,
+    tokens::{Token, TokenKind, TypeNameTokens},
+    Marker, ParseError, ParseErrorKind,
+};
+
+/// A type, as it appears in the output of [`core::any::type_name`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TypeExpr<'a> {
+    /// A path, such as `alloc::vec::Vec<u32>` or `<T as Trait>::Assoc`.
+    Path(TypePath<'a>),
+    /// A tuple, such as `()`, `(u32,)` or `(u32, bool)`.
+    Tuple(Vec<TypeExpr<'a>>),
+    /// A parenthesized type, such as `(dyn Any + Send)`.
+    Paren(Box<TypeExpr<'a>>),
+    /// An array, such as `[u8; 4]`.
+    Array {
+        /// The type of the elements.
+        element: Box<TypeExpr<'a>>,
+        /// The length, as written.
+        len: &'a str,
+    },
+    /// A slice, such as `[u8]`.
+    Slice(Box<TypeExpr<'a>>),
+    /// A reference, such as `&str` or `&'a mut T`.
+    Reference {
+        /// The lifetime of the reference, if written.
+        lifetime: Option<&'a str>,
+        /// Whether this is a `&mut` reference.
+        mutable: bool,
+        /// The type behind the reference.
+        referent: Box<TypeExpr<'a>>,
+    },
+    /// A raw pointer, such as `*const u8` or `*mut T`.
+    Pointer {
+        /// Whether this is a `*mut` pointer.
+        mutable: bool,
+        /// The type behind the pointer.
+        pointee: Box<TypeExpr<'a>>,
+    },
+    /// A function pointer, such as `fn(u32) -> bool`.
+    FnPointer(FnPointer<'a>),
+    /// A trait object, such as `dyn core::any::Any + core::marker::Send`.
+    TraitObject(Vec<Bound<'a>>),
+    /// An opaque type, such as `impl core::future::Future`.
+    ImplTrait(Vec<Bound<'a>>),
+    /// The never type, `!`.
+    Never,
+}
+
+/// A path to a type, function or other item.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TypePath<'a> {
+    /// The type this path is relative to, as in `<T as Trait>::Assoc`.
+    pub qself: Option<Box<QualifiedSelf<'a>>>,
+    /// The `::`-separated segments of the path.
+    pub segments: Vec<PathSegment<'a>>,
+}
+
+/// The self type of a qualified path, such as the `<T as Trait>` in `<T as Trait>::Assoc`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct QualifiedSelf<'a> {
+    /// The self type.
+    pub ty: TypeExpr<'a>,
+    /// The trait the self type is cast to, if any.
+    pub as_trait: Option<TypePath<'a>>,
+    /// Whether the self type is wrapped in `<..>`.
+    ///
+    /// This is `false` for names such as `(String, String)::default`.
+    pub angle_bracketed: bool,
+}
+
+/// A single segment of a [`TypePath`], such as `Vec<u32>`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PathSegment<'a> {
+    /// The identifier of the segment, such as `Vec` or `{{closure}}`.
+    pub ident: &'a str,
+    /// What the identifier stands for, if it is a marker generated by the compiler
+    /// such as `{impl#0}` or `{{closure}}`.
+    pub marker: Option<Marker<'a>>,
+    /// The generic arguments of the segment, if any.
+    pub args: Option<GenericArgs<'a>>,
+}
+
+/// The arguments of a [`PathSegment`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GenericArgs<'a> {
+    /// Angle bracketed arguments, such as the `<u32, bool>` in `HashMap<u32, bool>`.
+    AngleBracketed(Vec<GenericArg<'a>>),
+    /// Parenthesized arguments, such as the `(u32) -> bool` in `Fn(u32) -> bool`,
+    /// or the `(2)` in `Option::Some(2)`.
+    Parenthesized {
+        /// The arguments inside the parentheses.
+        inputs: Vec<GenericArg<'a>>,
+        /// The type after `->`, if any.
+        output: Option<Box<TypeExpr<'a>>>,
+    },
+}
+
+/// A single generic argument.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GenericArg<'a> {
+    /// A type argument, such as `u32`.
+    Type(TypeExpr<'a>),
+    /// A lifetime argument, such as `'static`.
+    Lifetime(&'a str),
+    /// A const argument, such as `3`, `true` or `{ N + 1 }`.
+    Const(&'a str),
+    /// An associated type binding, such as `Item = u32`.
+    Binding {
+        /// The name of the associated type.
+        name: &'a str,
+        /// The type bound to it.
+        ty: TypeExpr<'a>,
+    },
+}
+
+/// A bound of a trait object or opaque type.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Bound<'a> {
+    /// A trait bound, such as `core::marker::Send` or `for<'a> Fn(&'a u8)`.
+    Trait {
+        /// The lifetimes introduced by a `for<..>` binder.
+        bound_lifetimes: Vec<&'a str>,
+        /// The path to the trait.
+        path: TypePath<'a>,
+    },
+    /// A lifetime bound, such as `'static`.
+    Lifetime(&'a str),
+}
+
+/// A function pointer type, such as `unsafe extern "C" fn(u32) -> bool`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FnPointer<'a> {
+    /// The lifetimes introduced by a `for<..>` binder.
+    pub bound_lifetimes: Vec<&'a str>,
+    /// Whether the function is `unsafe`.
+    pub is_unsafe: bool,
+    /// The ABI of an `extern` function, without quotes.
+    pub abi: Option<&'a str>,
+    /// The parameter types.
+    pub inputs: Vec<TypeExpr<'a>>,
+    /// The return type, if any.
+    pub output: Option<Box<TypeExpr<'a>>>,
+}
+
+/// The deepest nesting of types which [`parse`] will accept.
+const MAX_DEPTH: usize = 128;
+
+/// Parses a type name, such as one returned by [`core::any::type_name`].
+///
+/// Whitespace between tokens is not significant, so the parsed name may render
+/// slightly differently to the input. Types nested more than 128 deep are
+/// rejected with [`ParseErrorKind::TooDeep`] rather than risking a stack overflow.
+pub fn parse(name: &str) -> Result<TypeExpr<'_>, ParseError> {
+    let mut parser = Parser::new(name);
+    let ty = parser.parse_type()?;
+    match parser.peek() {
+        Some(token) => Err(ParseError::new(token.offset, ParseErrorKind::TrailingInput)),
+        None => Ok(ty),
+    }
+}
+
+/// Parses a type name, guaranteeing that the [`Display`](fmt::Display) output of
+/// the parsed form is exactly the original name.
+///
+/// This never panics, whatever the input. Names which [`parse`] accepts but would
+/// render differently, for example because of extra whitespace, are rejected
+/// with [`ParseErrorKind::NonCanonical`] at the first byte which would differ.
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::{parse::try_parse, ParseErrorKind};
+/// let name = "core::option::Option<(u8, u16)>";
+/// assert_eq!(try_parse(name).unwrap().to_string(), name);
+///
+/// let error = try_parse("core::option::Option<(u8,u16)>").unwrap_err();
+/// assert_eq!(error.kind, ParseErrorKind::NonCanonical);
+/// assert_eq!(error.offset, 25);
+/// ```
+pub fn try_parse(name: &str) -> Result<TypeExpr<'_>, ParseError> {
+    let ty = parse(name)?;
+    let mut compare = crate::display::Compare {
+        expected: name,
+        offset: 0,
+    };
+    let matches = fmt::write(&mut compare, format_args!("{ty}")).is_ok();
+    if matches && compare.offset == name.len() {
+        Ok(ty)
+    } else {
+        Err(ParseError::new(
+            compare.offset,
+            ParseErrorKind::NonCanonical,
+        ))
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    tokens: TypeNameTokens<'a>,
+    /// How many types are currently being parsed, one inside another.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            tokens: TypeNameTokens::new(input),
+            depth: 0,
+        }
+    }
+
+    fn significant(tokens: &mut TypeNameTokens<'a>) -> Option<Token<'a>> {
+        tokens.find(|token| token.kind != TokenKind::Space)
+    }
+
+    fn peek(&self) -> Option<Token<'a>> {
+        Self::significant(&mut self.tokens.clone())
+    }
+
+    fn peek_second(&self) -> Option<Token<'a>> {
+        let mut tokens = self.tokens.clone();
+        Self::significant(&mut tokens);
+        Self::significant(&mut tokens)
+    }
+
+    fn bump(&mut self) -> Option<Token<'a>> {
+        Self::significant(&mut self.tokens)
+    }
+
+    fn is(&self, kind: TokenKind) -> bool {
+        matches!(self.peek(), Some(token) if token.kind == kind)
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(token) if token.kind == TokenKind::Ident && token.text == keyword)
+    }
+
+    fn eat(&mut self, kind: TokenKind) -> Option<Token<'a>> {
+        if self.is(kind) {
+            self.bump()
+        } else {
+            None
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let is_keyword = self.is_keyword(keyword);
+        if is_keyword {
+            self.bump();
+        }
+        is_keyword
+    }
+
+    fn expect(&mut self, kind: TokenKind) -> Result<Token<'a>, ParseError> {
+        match self.eat(kind) {
+            Some(token) => Ok(token),
+            None => Err(self.error(ParseErrorKind::UnexpectedToken)),
+        }
+    }
+
+    /// An error of the given kind at the next token, or at the end of the input.
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        match self.peek() {
+            Some(token) => ParseError::new(token.offset, kind),
+            None => ParseError::new(self.input.len(), ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    /// Parses comma-separated items up to and including the `close` token.
+    /// The opening token must already have been consumed.
+    fn parse_list<T>(
+        &mut self,
+        close: TokenKind,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = Vec::new();
+        while self.eat(close).is_none() {
+            items.push(item(self)?);
+            if self.eat(TokenKind::Comma).is_none() {
+                self.expect(close)?;
+                break;
+            }
+        }
+        Ok(items)
+    }
+
+    fn parse_type(&mut self) -> Result<TypeExpr<'a>, ParseError> {
+        // Every recursive rule passes through here, so this bounds the stack depth.
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(ParseErrorKind::TooDeep));
+        }
+        self.depth += 1;
+        let ty = self.parse_type_inner();
+        self.depth -= 1;
+        ty
+    }
+
+    fn parse_type_inner(&mut self) -> Result<TypeExpr<'a>, ParseError> {
+        let Some(token) = self.peek() else {
+            return Err(self.error(ParseErrorKind::ExpectedType));
+        };
+
+        let ty = match token.kind {
+            TokenKind::And => {
+                self.bump();
+                let lifetime = self.eat(TokenKind::Lifetime).map(|token| token.text);
+                let mutable = self.eat_keyword("mut");
+                TypeExpr::Reference {
+                    lifetime,
+                    mutable,
+                    referent: Box::new(self.parse_type()?),
+                }
+            }
+            TokenKind::Star => {
+                self.bump();
+                let mutable = if self.eat_keyword("mut") {
+                    true
+                } else if self.eat_keyword("const") {
+                    false
+                } else {
+                    return Err(self.error(ParseErrorKind::UnexpectedToken));
+                };
+                TypeExpr::Pointer {
+                    mutable,
+                    pointee: Box::new(self.parse_type()?),
+                }
+            }
+            TokenKind::OpenParen => {
+                self.bump();
+                let ty = if self.eat(TokenKind::CloseParen).is_some() {
+                    TypeExpr::Tuple(Vec::new())
+                } else {
+                    let first = self.parse_type()?;
+                    if self.eat(TokenKind::CloseParen).is_some() {
+                        TypeExpr::Paren(Box::new(first))
+                    } else {
+                        self.expect(TokenKind::Comma)?;
+                        let mut elements =
+                            self.parse_list(TokenKind::CloseParen, Self::parse_type)?;
+                        elements.insert(0, first);
+                        TypeExpr::Tuple(elements)
+                    }
+                };
+                return self.parse_continuation(ty);
+            }
+            TokenKind::OpenBracket => {
+                self.bump();
+                let element = Box::new(self.parse_type()?);
+                let ty = if self.eat(TokenKind::Semicolon).is_some() {
+                    let len = self.parse_array_len()?;
+                    TypeExpr::Array { element, len }
+                } else {
+                    self.expect(TokenKind::CloseBracket)?;
+                    TypeExpr::Slice(element)
+                };
+                return self.parse_continuation(ty);
+            }
+            TokenKind::Bang => {
+                self.bump();
+                TypeExpr::Never
+            }
+            TokenKind::Lt => TypeExpr::Path(self.parse_qualified_path()?),
+            TokenKind::Ident => match token.text {
+                "dyn" => {
+                    self.bump();
+                    TypeExpr::TraitObject(self.parse_bounds()?)
+                }
+                "impl" => {
+                    self.bump();
+                    TypeExpr::ImplTrait(self.parse_bounds()?)
+                }
+                "fn" | "unsafe" | "extern" => {
+                    TypeExpr::FnPointer(self.parse_fn_pointer(Vec::new())?)
+                }
+                "for" => {
+                    self.bump();
+                    let bound_lifetimes = self.parse_bound_lifetimes()?;
+                    TypeExpr::FnPointer(self.parse_fn_pointer(bound_lifetimes)?)
+                }
+                _ => TypeExpr::Path(self.parse_path()?),
+            },
+            TokenKind::OpenBrace => TypeExpr::Path(self.parse_path()?),
+            _ => return Err(self.error(ParseErrorKind::ExpectedType)),
+        };
+
+        Ok(ty)
+    }
+
+    /// Parses the path following a type which is not wrapped in `<..>`,
+    /// as in `(String, String)::default`.
+    fn parse_continuation(&mut self, ty: TypeExpr<'a>) -> Result<TypeExpr<'a>, ParseError> {
+        if self.eat(TokenKind::PathSep).is_none() {
+            return Ok(ty);
+        }
+
+        let qself = QualifiedSelf {
+            ty,
+            as_trait: None,
+            angle_bracketed: false,
+        };
+        Ok(TypeExpr::Path(TypePath {
+            qself: Some(Box::new(qself)),
+            segments: self.parse_segments()?,
+        }))
+    }
+
+    fn parse_array_len(&mut self) -> Result<&'a str, ParseError> {
+        let start = self.peek().map_or(self.input.len(), |token| token.offset);
+        let mut depth = 0_usize;
+        loop {
+            let Some(token) = self.bump() else {
+                return Err(self.error(ParseErrorKind::UnexpectedEnd));
+            };
+            match token.kind {
+                TokenKind::OpenBracket => depth += 1,
+                TokenKind::CloseBracket if depth == 0 => {
+                    let len = self.input[start..token.offset].trim_end();
+                    if len.is_empty() {
+                        return Err(ParseError::new(
+                            token.offset,
+                            ParseErrorKind::UnexpectedToken,
+                        ));
+                    }
+                    return Ok(len);
+                }
+                TokenKind::CloseBracket => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_qualified_path(&mut self) -> Result<TypePath<'a>, ParseError> {
+        self.expect(TokenKind::Lt)?;
+        let ty = self.parse_type()?;
+        let as_trait = if self.eat_keyword("as") {
+            Some(self.parse_path()?)
+        } else {
+            None
+        };
+        self.expect(TokenKind::Gt)?;
+        self.expect(TokenKind::PathSep)?;
+
+        let qself = QualifiedSelf {
+            ty,
+            as_trait,
+            angle_bracketed: true,
+        };
+        Ok(TypePath {
+            qself: Some(Box::new(qself)),
+            segments: self.parse_segments()?,
+        })
+    }
+
+    fn parse_path(&mut self) -> Result<TypePath<'a>, ParseError> {
+        Ok(TypePath {
+            qself: None,
+            segments: self.parse_segments()?,
+        })
+    }
+
+    fn parse_segments(&mut self) -> Result<Vec<PathSegment<'a>>, ParseError> {
+        let mut segments = Vec::new();
+        loop {
+            segments.push(self.parse_segment()?);
+            if self.eat(TokenKind::PathSep).is_none() {
+                return Ok(segments);
+            }
+        }
+    }
+
+    fn parse_segment(&mut self) -> Result<PathSegment<'a>, ParseError> {
+        let ident = match self.peek() {
+            Some(token) if token.kind == TokenKind::Ident => {
+                self.bump();
+                token.text
+            }
+            Some(token) if token.kind == TokenKind::OpenBrace => self.parse_braced()?,
+            _ => return Err(self.error(ParseErrorKind::ExpectedSegment)),
+        };
+
+        let args = if self.eat(TokenKind::Lt).is_some() {
+            Some(GenericArgs::AngleBracketed(
+                self.parse_list(TokenKind::Gt, Self::parse_generic_arg)?,
+            ))
+        } else if self.eat(TokenKind::OpenParen).is_some() {
+            let inputs = self.parse_list(TokenKind::CloseParen, Self::parse_generic_arg)?;
+            Some(GenericArgs::Parenthesized {
+                inputs,
+                output: self.parse_output()?,
+            })
+        } else {
+            None
+        };
+
+        Ok(PathSegment {
+            ident,
+            marker: Marker::parse(ident),
+            args,
+        })
+    }
+
+    /// Parses a balanced `{..}` group, such as `{{closure}}`, returning its text.
+    fn parse_braced(&mut self) -> Result<&'a str, ParseError> {
+        let open = self.expect(TokenKind::OpenBrace)?;
+        let mut depth = 1_usize;
+        while depth > 0 {
+            let Some(token) = self.tokens.next() else {
+                return Err(ParseError::new(
+                    self.input.len(),
+                    ParseErrorKind::UnexpectedEnd,
+                ));
+            };
+            match token.kind {
+                TokenKind::OpenBrace => depth += 1,
+                TokenKind::CloseBrace => depth -= 1,
+                _ => {}
+            }
+        }
+        let end = self.consumed_offset();
+        Ok(&self.input[open.offset..end])
+    }
+
+    /// The byte offset just past the last consumed token.
+    fn consumed_offset(&self) -> usize {
+        self.tokens
+            .clone()
+            .next()
+            .map_or(self.input.len(), |token| token.offset)
+    }
+
+    fn parse_generic_arg(&mut self) -> Result<GenericArg<'a>, ParseError> {
+        let Some(token) = self.peek() else {
+            return Err(self.error(ParseErrorKind::ExpectedType));
+        };
+
+        match token.kind {
+            TokenKind::Lifetime => {
+                self.bump();
+                Ok(GenericArg::Lifetime(token.text))
+            }
+            TokenKind::Literal => {
+                self.bump();
+                Ok(GenericArg::Const(token.text))
+            }
+            TokenKind::OpenBrace => Ok(GenericArg::Const(self.parse_braced()?)),
+            TokenKind::Ident if matches!(token.text, "true" | "false") => {
+                self.bump();
+                Ok(GenericArg::Const(token.text))
+            }
+            TokenKind::Ident if matches!(self.peek_second(), Some(next) if next.kind == TokenKind::Eq) =>
+            {
+                self.bump();
+                self.bump();
+                Ok(GenericArg::Binding {
+                    name: token.text,
+                    ty: self.parse_type()?,
+                })
+            }
+            _ => Ok(GenericArg::Type(self.parse_type()?)),
+        }
+    }
+
+    fn parse_output(&mut self) -> Result<Option<Box<TypeExpr<'a>>>, ParseError> {
+        if self.eat(TokenKind::Arrow).is_some() {
+            Ok(Some(Box::new(self.parse_type()?)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn parse_bounds(&mut self) -> Result<Vec<Bound<'a>>, ParseError> {
+        let mut bounds = Vec::new();
+        loop {
+            let bound = if let Some(token) = self.eat(TokenKind::Lifetime) {
+                Bound::Lifetime(token.text)
+            } else {
+                let bound_lifetimes = if self.eat_keyword("for") {
+                    self.parse_bound_lifetimes()?
+                } else {
+                    Vec::new()
+                };
+                Bound::Trait {
+                    bound_lifetimes,
+                    path: self.parse_path()?,
+                }
+            };
+            bounds.push(bound);
+
+            if self.eat(TokenKind::Plus).is_none() {
+                return Ok(bounds);
+            }
+        }
+    }
+
+    /// Parses the `<'a, 'b>` following a `for` keyword.
+    fn parse_bound_lifetimes(&mut self) -> Result<Vec<&'a str>, ParseError> {
+        self.expect(TokenKind::Lt)?;
+        self.parse_list(TokenKind::Gt, |parser| {
+            parser.expect(TokenKind::Lifetime).map(|token| token.text)
+        })
+    }
+
+    fn parse_fn_pointer(
+        &mut self,
+        bound_lifetimes: Vec<&'a str>,
+    ) -> Result<FnPointer<'a>, ParseError> {
+        let is_unsafe = self.eat_keyword("unsafe");
+        let abi = if self.eat_keyword("extern") {
+            let error = self.error(ParseErrorKind::UnexpectedToken);
+            let literal = self.expect(TokenKind::Literal)?;
+            let abi = literal
+                .text
+                .strip_prefix('"')
+                .and_then(|abi| abi.strip_suffix('"'))
+                .ok_or(error)?;
+            Some(abi)
+        } else {
+            None
+        };
+        if !self.eat_keyword("fn") {
+            return Err(self.error(ParseErrorKind::UnexpectedToken));
+        }
+
+        self.expect(TokenKind::OpenParen)?;
+        let inputs = self.parse_list(TokenKind::CloseParen, Self::parse_type)?;
+
+        Ok(FnPointer {
+            bound_lifetimes,
+            is_unsafe,
+            abi,
+            inputs,
+            output: self.parse_output()?,
+        })
+    }
+}
+
+impl<'a> TypeExpr<'a> {
+    /// Creates a path consisting of a single segment, such as a placeholder to
+    /// [`substitute`](Self::substitute) for a generic argument.
+    pub fn named(ident: &'a str) -> Self {
+        TypeExpr::Path(TypePath {
+            qself: None,
+            segments: vec![PathSegment {
+                ident,
+                marker: Marker::parse(ident),
+                args: None,
+            }],
+        })
+    }
+
+    /// Replaces the generic argument at `index` of the outermost path, returning the
+    /// argument it replaced.
+    ///
+    /// Returns [`None`] and leaves the type unchanged if this is not a path, or the
+    /// path has no such argument.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::parse::{parse, GenericArg, TypeExpr};
+    /// let mut ty = parse("bevy_asset::Assets<bevy_scene::DynamicScene>").unwrap();
+    /// ty.substitute(0, GenericArg::Type(TypeExpr::named("…")));
+    /// assert_eq!(ty.to_string(), "bevy_asset::Assets<…>");
+    /// assert_eq!(ty.shortened().to_string(), "Assets<…>");
+    /// ```
+    pub fn substitute(
+        &mut self,
+        index: usize,
+        replacement: GenericArg<'a>,
+    ) -> Option<GenericArg<'a>> {
+        match self {
+            TypeExpr::Path(path) => path.substitute(index, replacement),
+            _ => None,
+        }
+    }
+
+    /// Gets a view of this type which displays with module paths removed,
+    /// in the same way as [`ShortName`](crate::ShortName).
+    pub fn shortened(&self) -> Shortened<'_, 'a> {
+        Shortened(self)
+    }
+}
+
+impl<'a> TypePath<'a> {
+    /// Replaces the generic argument at `index` of the first segment with generic
+    /// arguments, returning the argument it replaced.
+    ///
+    /// Returns [`None`] and leaves the path unchanged if there is no such argument.
+    pub fn substitute(
+        &mut self,
+        index: usize,
+        replacement: GenericArg<'a>,
+    ) -> Option<GenericArg<'a>> {
+        let args = self
+            .segments
+            .iter_mut()
+            .find_map(|segment| match &mut segment.args {
+                Some(GenericArgs::AngleBracketed(args)) => Some(args),
+                _ => None,
+            })?;
+        args.get_mut(index)
+            .map(|arg| core::mem::replace(arg, replacement))
+    }
+}
+
+/// Displays a [`TypeExpr`] with module paths removed.
+///
+/// Returned by [`TypeExpr::shortened`].
+#[derive(Clone, Copy, Debug)]
+pub struct Shortened<'t, 'a>(&'t TypeExpr<'a>);
+
+impl<'t, 'a> fmt::Display for Shortened<'t, 'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Renderer::SHORT.expr(f, self.0)
+    }
+}
+
+impl<'a> fmt::Display for TypeExpr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Renderer::FULL.expr(f, self)
+    }
+}
+
+impl<'a> fmt::Display for TypePath<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Renderer::FULL.path(f, self)
+    }
+}
+
+impl<'a> fmt::Display for QualifiedSelf<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Renderer::FULL.qself(f, self)
+    }
+}
+
+impl<'a> fmt::Display for PathSegment<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Renderer::FULL.segment(f, self)
+    }
+}
+
+impl<'a> fmt::Display for GenericArgs<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Renderer::FULL.args(f, self)
+    }
+}
+
+impl<'a> fmt::Display for GenericArg<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Renderer::FULL.arg(f, self)
+    }
+}
+
+impl<'a> fmt::Display for Bound<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Renderer::FULL.bound(f, self)
+    }
+}
+
+impl<'a> fmt::Display for FnPointer<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Renderer::FULL.fn_pointer(f, self)
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+    use crate::MarkerKind;
+    use alloc::string::ToString;
+
+    #[track_caller]
+    fn round_trip(name: &str) {
+        assert_eq!(parse(name).unwrap().to_string(), name);
+    }
+
+    #[test]
+    fn paths() {
+        round_trip("test_system");
+        round_trip("bevy_prelude::make_fun_game");
+        round_trip("core::option::Option::None");
+        round_trip("core::option::Option::Some(2)");
+    }
+
+    #[test]
+    fn generics() {
+        round_trip("alloc::vec::Vec<core::option::Option<u32>>");
+        round_trip("std::collections::hash::map::HashMap<u32, alloc::string::String>");
+        round_trip("bevy_asset::assets::Assets<bevy_scene::dynamic_scene::DynamicScene>::asset_event_system");
+        round_trip("arrayvec::ArrayVec<u8, 16>");
+        round_trip("foo::Flag<true>");
+        round_trip("foo::Borrowed<'static, str>");
+
+        let TypeExpr::Path(path) = parse("a::B<C, 3, 'a>").unwrap() else {
+            panic!("expected a path");
+        };
+        assert_eq!(path.segments.len(), 2);
+        let Some(GenericArgs::AngleBracketed(args)) = &path.segments[1].args else {
+            panic!("expected generic arguments");
+        };
+        assert!(matches!(args[0], GenericArg::Type(_)));
+        assert_eq!(args[1], GenericArg::Const("3"));
+        assert_eq!(args[2], GenericArg::Lifetime("'a"));
+    }
+
+    #[test]
+    fn tuples_and_arrays() {
+        round_trip("()");
+        round_trip("(u32,)");
+        round_trip("(alloc::string::String, alloc::string::String)");
+        round_trip("(alloc::string::String, alloc::string::String)::default");
+        round_trip("[i32; 3]");
+        round_trip("[i32; 16]::default");
+        round_trip("[u8]");
+        round_trip("&[&str]");
+    }
+
+    #[test]
+    fn pointers_and_references() {
+        round_trip("&str");
+        round_trip("&mut alloc::vec::Vec<u8>");
+        round_trip("&'a mut T");
+        round_trip("*const u8");
+        round_trip("*mut core::ffi::c_void");
+        round_trip("&(dyn core::any::Any + core::marker::Send)");
+    }
+
+    #[test]
+    fn functions_and_traits() {
+        round_trip("fn()");
+        round_trip("fn(u32, bool) -> u32");
+        round_trip("unsafe extern \"C\" fn(*const u8) -> !");
+        round_trip("for<'a> fn(&'a u8) -> &'a u8");
+        round_trip("dyn core::ops::function::Fn(u32) -> u32 + core::marker::Send");
+        round_trip("dyn core::iter::traits::iterator::Iterator<Item = u32>");
+        round_trip("dyn core::any::Any + 'static");
+        round_trip("impl core::future::future::Future<Output = ()>");
+        round_trip("<T as core::default::Default>::default");
+        round_trip("<[T]>::len");
+        round_trip("my_crate::system::{{closure}}");
+    }
+
+    #[test]
+    fn markers() {
+        let name = "<my_game::Player as my_game::{impl#0}::Spawn>::spawn::{closure#1}::{{closure}}";
+        round_trip(name);
+
+        let TypeExpr::Path(path) = parse(name).unwrap() else {
+            panic!("expected a path");
+        };
+        let markers: Vec<_> = path
+            .segments
+            .iter()
+            .map(|segment| segment.marker.map(|marker| (marker.kind, marker.index)))
+            .collect();
+        assert_eq!(
+            markers,
+            [
+                None,
+                Some((MarkerKind::Closure, Some(1))),
+                Some((MarkerKind::Closure, None)),
+            ]
+        );
+
+        let TypeExpr::Array { len, .. } = parse("[u8; {constant#0}]").unwrap() else {
+            panic!("expected an array");
+        };
+        assert_eq!(len, "{constant#0}");
+        let impl_marker = Marker::parse("{impl#12}").unwrap();
+        assert_eq!(impl_marker.kind, MarkerKind::Impl);
+        assert_eq!(impl_marker.index, Some(12));
+        assert_eq!(Marker::parse("{foo#x}").unwrap().kind, MarkerKind::Other);
+    }
+
+    #[test]
+    fn shortened() {
+        let shortened = |name| parse(name).unwrap().shortened().to_string();
+
+        assert_eq!(
+            shortened("alloc::vec::Vec<core::option::Option<u32>>"),
+            "Vec<Option<u32>>"
+        );
+        assert_eq!(
+            shortened("bevy_render::RenderSet::Prepare"),
+            "RenderSet::Prepare"
+        );
+        assert_eq!(
+            shortened("bevy_asset::assets::Assets<bevy_scene::DynamicScene>::asset_event_system"),
+            "Assets<DynamicScene>::asset_event_system"
+        );
+        assert_eq!(
+            shortened("<alloc::vec::Vec<u8> as core::default::Default>::default"),
+            "<Vec<u8> as Default>::default"
+        );
+        assert_eq!(shortened("&mut alloc::string::String"), "&mut String");
+        assert_eq!(
+            shortened("dyn core::ops::function::Fn(a::B) -> c::D + core::marker::Send"),
+            "dyn Fn(B) -> D + Send"
+        );
+    }
+
+    #[test]
+    fn substitute() {
+        let mut ty = parse("std::collections::HashMap<a::K, b::V>").unwrap();
+        let old = ty.substitute(1, GenericArg::Type(TypeExpr::named("T")));
+        assert_eq!(old.unwrap().to_string(), "b::V");
+        assert_eq!(ty.to_string(), "std::collections::HashMap<a::K, T>");
+        assert_eq!(ty.shortened().to_string(), "HashMap<K, T>");
+
+        assert_eq!(ty.substitute(2, GenericArg::Const("3")), None);
+        assert_eq!(ty.to_string(), "std::collections::HashMap<a::K, T>");
+
+        let mut ty = parse("(a::B<C>, D)").unwrap();
+        assert_eq!(ty.substitute(0, GenericArg::Const("3")), None);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse("Vec<u32"),
+            Err(ParseError::new(7, ParseErrorKind::UnexpectedEnd))
+        );
+        assert_eq!(
+            parse("a::"),
+            Err(ParseError::new(3, ParseErrorKind::UnexpectedEnd))
+        );
+        assert_eq!(
+            parse("a>"),
+            Err(ParseError::new(1, ParseErrorKind::TrailingInput))
+        );
+        assert_eq!(
+            parse("a::,"),
+            Err(ParseError::new(3, ParseErrorKind::ExpectedSegment))
+        );
+        assert_eq!(
+            parse(","),
+            Err(ParseError::new(0, ParseErrorKind::ExpectedType))
+        );
+        assert_eq!(
+            parse(&"&".repeat(1000)),
+            Err(ParseError::new(128, ParseErrorKind::TooDeep))
+        );
+        assert_eq!(
+            parse(&"a<".repeat(1000)),
+            Err(ParseError::new(256, ParseErrorKind::TooDeep))
+        );
+    }
+
+    #[test]
+    fn try_parse_round_trips() {
+        let name = "(alloc::vec::Vec<&'a mut [u8; 3]>, dyn a::B<C = D> + Send)";
+        assert_eq!(try_parse(name).unwrap().to_string(), name);
+
+        assert_eq!(
+            try_parse("a::B< C>"),
+            Err(ParseError::new(5, ParseErrorKind::NonCanonical))
+        );
+        assert_eq!(
+            try_parse("a::B "),
+            Err(ParseError::new(4, ParseErrorKind::NonCanonical))
+        );
+        assert_eq!(
+            try_parse("a::B<"),
+            Err(ParseError::new(5, ParseErrorKind::UnexpectedEnd))
+        );
+    }
+
+    #[test]
+    fn try_parse_arbitrary_input() {
+        const PIECES: &[&str] = &[
+            "a", "B", "::", "<", ">", "(", ")", "[", "]", "{", "}", ",", ";", " ", "&", "*", "'a",
+            "'x'", "3", "dyn ", "fn", "mut ", "const ", "for", "->", "+", "=", "!", "\"", "é",
+        ];
+
+        // A deterministic linear congruential generator suffices to explore the input space.
+        let mut state = 0x2545_f491_u32;
+        for _ in 0..20_000 {
+            let mut name = alloc::string::String::new();
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            for _ in 0..(state >> 16) % 12 {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                name.push_str(PIECES[(state >> 16) as usize % PIECES.len()]);
+            }
+            if let Ok(ty) = try_parse(&name) {
+                assert_eq!(ty.to_string(), name);
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use alloc::{boxed::Box, string::String};
+use core::any::TypeId;
+use std::collections::HashMap;
+
+use crate::ShortName;
+
+/// Maps types to their shortened names, shortening each one only the first time
+/// it is asked for.
+///
+/// Lookups hash a [`TypeId`] rather than the name, so this suits code which looks
+/// up the names of the same types over and over, such as every frame.
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::ShortNameRegistry;
+/// let mut registry = ShortNameRegistry::new();
+/// assert_eq!(registry.get_or_insert::<Vec<Option<u32>>>(), "Vec<Option<u32>>");
+/// assert_eq!(registry.get::<Vec<Option<u32>>>(), Some("Vec<Option<u32>>"));
+/// assert_eq!(registry.get::<String>(), None);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ShortNameRegistry {
+    names: HashMap<TypeId, Box<str>>,
+}
+
+impl ShortNameRegistry {
+    /// Creates a registry with no names in it.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the shortened name of `T`, shortening and storing it if this is the
+    /// first time it is asked for.
+    pub fn get_or_insert<T: ?Sized + 'static>(&mut self) -> &str {
+        self.names
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| String::from(ShortName::of::<T>()).into_boxed_str())
+    }
+
+    /// Gets the shortened name of `T`, if it was stored before.
+    pub fn get<T: ?Sized + 'static>(&self) -> Option<&str> {
+        self.names.get(&TypeId::of::<T>()).map(|name| &**name)
+    }
+
+    /// Gets the number of types whose names are stored.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Whether no names are stored.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Removes all stored names, such as after changing the
+    /// [default configuration](crate::set_default_config).
+    pub fn clear(&mut self) {
+        self.names.clear();
+    }
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use super::ShortNameRegistry;
+
+    struct Player;
+
+    #[test]
+    fn stores_each_type_once() {
+        let mut registry = ShortNameRegistry::new();
+        assert!(registry.is_empty());
+        assert_eq!(registry.get::<Player>(), None);
+
+        assert_eq!(registry.get_or_insert::<Player>(), "Player");
+        assert_eq!(registry.get_or_insert::<Option<Player>>(), "Option<Player>");
+        assert_eq!(registry.get_or_insert::<Player>(), "Player");
+        assert_eq!(registry.get_or_insert::<str>(), "str");
+        assert_eq!(registry.len(), 3);
+        assert_eq!(registry.get::<Player>(), Some("Player"));
+
+        registry.clear();
+        assert!(registry.is_empty());
+    }
+}
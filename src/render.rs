@@ -0,0 +1,440 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use crate::{
+    parse::{
+        Bound, FnPointer, GenericArg, GenericArgs, PathSegment, QualifiedSelf, TypeExpr, TypePath,
+    },
+    ClosureStyle, GenericArgsStyle, MarkerKind, ShortNameConfig, TupleStyle,
+};
+
+/// Renders a parsed type name as text, following a [`ShortNameConfig`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Renderer<'c> {
+    pub(crate) config: &'c ShortNameConfig,
+    /// How many generic argument lists the renderer is inside.
+    pub(crate) depth: usize,
+}
+
+impl Renderer<'static> {
+    /// Renders the full name.
+    pub(crate) const FULL: Self = Self {
+        config: &ShortNameConfig::new().shorten_paths(false),
+        depth: 0,
+    };
+
+    /// Renders the shortened name.
+    pub(crate) const SHORT: Self = Self {
+        config: &ShortNameConfig::new(),
+        depth: 0,
+    };
+}
+
+impl<'c> Renderer<'c> {
+    /// Renders the name following `config`.
+    pub(crate) const fn new(config: &'c ShortNameConfig) -> Self {
+        Self { config, depth: 0 }
+    }
+
+    pub(crate) fn expr(self, w: &mut dyn Write, expr: &TypeExpr<'_>) -> fmt::Result {
+        match expr {
+            TypeExpr::Path(path) => self.path(w, path),
+            TypeExpr::Tuple(elements) if elements.len() > self.config.max_tuple_len => {
+                let kept = &elements[..self.config.max_tuple_len];
+                match self.config.long_tuples {
+                    TupleStyle::Truncate if kept.is_empty() => w.write_str("(…)"),
+                    TupleStyle::Truncate => {
+                        w.write_str("(")?;
+                        self.join(w, kept, ", ", Self::expr)?;
+                        w.write_str(", …)")
+                    }
+                    TupleStyle::Count => write!(w, "({} elements)", elements.len()),
+                }
+            }
+            TypeExpr::Tuple(elements) => {
+                w.write_str("(")?;
+                self.join(w, elements, ", ", Self::expr)?;
+                if elements.len() == 1 {
+                    w.write_str(",")?;
+                }
+                w.write_str(")")
+            }
+            TypeExpr::Paren(inner) => {
+                w.write_str("(")?;
+                self.expr(w, inner)?;
+                w.write_str(")")
+            }
+            TypeExpr::Array { element, len } => {
+                w.write_str("[")?;
+                self.expr(w, element)?;
+                write!(w, "; {len}]")
+            }
+            TypeExpr::Slice(element) => {
+                w.write_str("[")?;
+                self.expr(w, element)?;
+                w.write_str("]")
+            }
+            TypeExpr::Reference {
+                lifetime,
+                mutable,
+                referent,
+            } => {
+                w.write_str("&")?;
+                match lifetime {
+                    Some(lifetime) if !self.config.strip_lifetimes => write!(w, "{lifetime} ")?,
+                    _ => {}
+                }
+                if *mutable {
+                    w.write_str("mut ")?;
+                }
+                self.expr(w, referent)
+            }
+            TypeExpr::Pointer { mutable, pointee } => {
+                w.write_str(if *mutable { "*mut " } else { "*const " })?;
+                self.expr(w, pointee)
+            }
+            TypeExpr::FnPointer(fn_pointer) => self.fn_pointer(w, fn_pointer),
+            TypeExpr::TraitObject(bounds) => {
+                if self.config.dyn_keyword {
+                    w.write_str("dyn ")?;
+                }
+                self.join(w, self.visible_bounds(bounds), " + ", Self::bound)
+            }
+            TypeExpr::ImplTrait(bounds) => {
+                w.write_str("impl ")?;
+                self.join(w, self.visible_bounds(bounds), " + ", Self::bound)
+            }
+            TypeExpr::Never => w.write_str("!"),
+        }
+    }
+
+    pub(crate) fn path(self, w: &mut dyn Write, path: &TypePath<'_>) -> fmt::Result {
+        if let Some(qself) = &path.qself {
+            self.qself(w, qself)?;
+            w.write_str("::")?;
+        }
+
+        if path.qself.is_none() {
+            if let Some((display, len)) = self.config.alias_for(&path.segments) {
+                w.write_str(display)?;
+                if let Some(args) = &path.segments[len - 1].args {
+                    self.args(w, args)?;
+                }
+                for segment in &path.segments[len..] {
+                    if self.is_dropped_closure(segment) {
+                        continue;
+                    }
+                    w.write_str("::")?;
+                    self.segment(w, segment)?;
+                }
+                return Ok(());
+            }
+        }
+
+        // The crate is the first segment of a path not relative to a type.
+        let crate_name = match &path.segments[..] {
+            [first, _, ..] if path.qself.is_none() => Some(first.ident),
+            _ => None,
+        };
+        // Paths outside of generic arguments may be kept in full.
+        let outer = self.depth == 0 && self.config.keep_outer_paths;
+        let shorten = !outer && self.config.shortens(crate_name);
+
+        // Segments are shortened in groups ending at a segment with arguments,
+        // so that `a::B<c::D>::e::f` becomes `B<D>::f`.
+        let mut first = true;
+        let mut start = 0;
+        for (index, segment) in path.segments.iter().enumerate() {
+            if segment.args.is_none() && index + 1 < path.segments.len() {
+                continue;
+            }
+
+            let group = &path.segments[start..=index];
+            let kept = if shorten {
+                // Closures are kept along with what they are defined in, unless
+                // they are displayed as is, like `ShortName` does.
+                let closures = match self.config.closures {
+                    ClosureStyle::Keep => 0,
+                    _ => group.iter().rev().take_while(|s| is_closure(s)).count(),
+                };
+                let named = &group[..group.len() - closures];
+                kept_segments(named, self.config.keep_segments) + closures
+            } else {
+                group.len()
+            };
+            let keep_crate = start == 0 && crate_name.is_some() && self.config.keep_crate;
+            for (position, segment) in group.iter().enumerate() {
+                let dropped = position < group.len() - kept && !(keep_crate && position == 0);
+                let abbreviated = dropped && self.config.abbreviate_modules;
+                if (dropped && !(abbreviated && is_abbreviable(segment.ident)))
+                    || self.is_dropped_closure(segment)
+                {
+                    continue;
+                }
+                if !first {
+                    w.write_str("::")?;
+                }
+                first = false;
+                if abbreviated {
+                    write_initials(w, segment.ident)?;
+                } else {
+                    self.segment(w, segment)?;
+                }
+            }
+            start = index + 1;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn qself(self, w: &mut dyn Write, qself: &QualifiedSelf<'_>) -> fmt::Result {
+        if !qself.angle_bracketed {
+            return self.expr(w, &qself.ty);
+        }
+        w.write_str("<")?;
+        self.expr(w, &qself.ty)?;
+        if let Some(as_trait) = &qself.as_trait {
+            w.write_str(" as ")?;
+            self.path(w, as_trait)?;
+        }
+        w.write_str(">")
+    }
+
+    pub(crate) fn segment(self, w: &mut dyn Write, segment: &PathSegment<'_>) -> fmt::Result {
+        match (&self.config.closures, segment.marker) {
+            (ClosureStyle::Replace(token), _) if is_closure(segment) => w.write_str(token)?,
+            (_, Some(marker)) if self.config.strip_disambiguators && marker.index.is_some() => {
+                write!(w, "{{{}}}", marker.name)?;
+            }
+            _ => w.write_str(segment.ident)?,
+        }
+        match &segment.args {
+            Some(args) => self.args(w, args),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn args(self, w: &mut dyn Write, args: &GenericArgs<'_>) -> fmt::Result {
+        match args {
+            // Lists of only hidden arguments, such as lifetimes when they are stripped,
+            // disappear entirely.
+            GenericArgs::AngleBracketed(args)
+                if !args.is_empty() && self.visible_args(args).next().is_none() =>
+            {
+                Ok(())
+            }
+            GenericArgs::AngleBracketed(args) => match self.generic_args_style(args) {
+                GenericArgsStyle::Show => {
+                    let nested = Self {
+                        depth: self.depth + 1,
+                        ..self
+                    };
+                    w.write_str("<")?;
+                    nested.join(w, self.visible_args(args), ", ", Self::arg)?;
+                    w.write_str(">")
+                }
+                GenericArgsStyle::Hide => Ok(()),
+                GenericArgsStyle::Ellipsis => w.write_str("<…>"),
+                GenericArgsStyle::Count => write!(w, "<{}>", self.visible_args(args).count()),
+            },
+            GenericArgs::Parenthesized { inputs, output } => {
+                w.write_str("(")?;
+                self.join(w, inputs, ", ", Self::arg)?;
+                w.write_str(")")?;
+                self.output(w, output.as_deref())
+            }
+        }
+    }
+
+    pub(crate) fn arg(self, w: &mut dyn Write, arg: &GenericArg<'_>) -> fmt::Result {
+        match arg {
+            GenericArg::Type(ty) => self.expr(w, ty),
+            GenericArg::Lifetime(lifetime) => w.write_str(lifetime),
+            GenericArg::Const(value) => w.write_str(value),
+            GenericArg::Binding { name, ty } => {
+                write!(w, "{name} = ")?;
+                self.expr(w, ty)
+            }
+        }
+    }
+
+    pub(crate) fn bound(self, w: &mut dyn Write, bound: &Bound<'_>) -> fmt::Result {
+        match bound {
+            Bound::Trait {
+                bound_lifetimes,
+                path,
+            } => {
+                self.bound_lifetimes(w, bound_lifetimes)?;
+                self.path(w, path)
+            }
+            Bound::Lifetime(lifetime) => w.write_str(lifetime),
+        }
+    }
+
+    pub(crate) fn fn_pointer(self, w: &mut dyn Write, fn_pointer: &FnPointer<'_>) -> fmt::Result {
+        self.bound_lifetimes(w, &fn_pointer.bound_lifetimes)?;
+        if fn_pointer.is_unsafe {
+            w.write_str("unsafe ")?;
+        }
+        if let Some(abi) = fn_pointer.abi {
+            write!(w, "extern \"{abi}\" ")?;
+        }
+        w.write_str("fn(")?;
+        self.join(w, &fn_pointer.inputs, ", ", Self::expr)?;
+        w.write_str(")")?;
+        self.output(w, fn_pointer.output.as_deref())
+    }
+
+    fn visible_args<'i, 'a>(
+        self,
+        args: &'i [GenericArg<'a>],
+    ) -> impl Iterator<Item = &'i GenericArg<'a>> {
+        let mut args = args;
+        if self.config.hide_default_args {
+            while let [rest @ .., last] = args {
+                if !is_default_arg(last) {
+                    break;
+                }
+                args = rest;
+            }
+        }
+        let strip = self.config.strip_lifetimes;
+        args.iter()
+            .filter(move |arg| !(strip && matches!(arg, GenericArg::Lifetime(_))))
+    }
+
+    fn visible_bounds<'i, 'a>(self, bounds: &'i [Bound<'a>]) -> Vec<&'i Bound<'a>> {
+        let strip = self.config.strip_lifetimes;
+        let mut visible: Vec<_> = bounds
+            .iter()
+            .filter(|bound| !(strip && matches!(bound, Bound::Lifetime(_))))
+            .collect();
+        if self.config.sort_bounds {
+            visible.sort_by_key(|bound| bound_rank(bound));
+        }
+        visible
+    }
+
+    fn is_dropped_closure(self, segment: &PathSegment<'_>) -> bool {
+        self.config.closures == ClosureStyle::Drop && is_closure(segment)
+    }
+
+    fn generic_args_style(self, args: &[GenericArg<'_>]) -> GenericArgsStyle {
+        if args.is_empty() {
+            // There is nothing to hide in `Foo<>`.
+            GenericArgsStyle::Show
+        } else if self.depth >= self.config.max_generic_depth {
+            self.config.deep_generic_args
+        } else {
+            self.config.generic_args
+        }
+    }
+
+    fn output(self, w: &mut dyn Write, output: Option<&TypeExpr<'_>>) -> fmt::Result {
+        match output {
+            Some(output) => {
+                w.write_str(" -> ")?;
+                self.expr(w, output)
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn bound_lifetimes(self, w: &mut dyn Write, lifetimes: &[&str]) -> fmt::Result {
+        if lifetimes.is_empty() || self.config.strip_lifetimes {
+            return Ok(());
+        }
+        w.write_str("for<")?;
+        self.join(w, lifetimes, ", ", |_, w, lifetime| w.write_str(lifetime))?;
+        w.write_str("> ")
+    }
+
+    /// Renders `items` separated by `separator`.
+    fn join<'i, T: 'i>(
+        self,
+        w: &mut dyn Write,
+        items: impl IntoIterator<Item = &'i T>,
+        separator: &str,
+        mut render: impl FnMut(Self, &mut dyn Write, &T) -> fmt::Result,
+    ) -> fmt::Result {
+        for (index, item) in items.into_iter().enumerate() {
+            if index > 0 {
+                w.write_str(separator)?;
+            }
+            render(self, w, item)?;
+        }
+        Ok(())
+    }
+}
+
+/// How many of the trailing segments in `group` survive shortening, given that
+/// at least `keep` should.
+///
+/// Like [`ShortName`](crate::ShortName), this keeps the segment before the last
+/// when it starts with an uppercase letter, assuming it to be an enum.
+fn kept_segments(group: &[PathSegment<'_>], keep: usize) -> usize {
+    let enum_variant = match group {
+        [.., second_last, _] if second_last.ident.starts_with(char::is_uppercase) => 2,
+        _ => 1,
+    };
+    keep.max(enum_variant).min(group.len())
+}
+
+/// The auto traits, in the order [`bound_rank`] puts them in.
+const AUTO_TRAITS: &[&str] = &["Send", "Sync", "Unpin", "UnwindSafe", "RefUnwindSafe"];
+
+/// Where `bound` goes when bounds are sorted: other traits first, in their
+/// original order, then auto traits, then lifetimes.
+fn bound_rank(bound: &Bound<'_>) -> usize {
+    match bound {
+        Bound::Trait { path, .. } => path
+            .segments
+            .last()
+            .and_then(|segment| AUTO_TRAITS.iter().position(|name| *name == segment.ident))
+            .map_or(0, |position| position + 1),
+        Bound::Lifetime(_) => AUTO_TRAITS.len() + 1,
+    }
+}
+
+/// The types which the standard library uses as defaults for generic parameters,
+/// such as the allocator of `Vec<T, A = Global>`.
+const DEFAULT_ARGS: &[&str] = &["Global", "RandomState"];
+
+/// Whether `arg` is one of the [`DEFAULT_ARGS`], either on its own or as a path
+/// into the standard library.
+fn is_default_arg(arg: &GenericArg<'_>) -> bool {
+    let GenericArg::Type(TypeExpr::Path(path)) = arg else {
+        return false;
+    };
+    match path.segments.as_slice() {
+        [first, .., last] if path.qself.is_none() => {
+            crate::kind::is_std_crate(first.ident)
+                && last.args.is_none()
+                && DEFAULT_ARGS.contains(&last.ident)
+        }
+        [only] => path.qself.is_none() && only.args.is_none() && DEFAULT_ARGS.contains(&only.ident),
+        _ => false,
+    }
+}
+
+fn is_closure(segment: &PathSegment<'_>) -> bool {
+    matches!(segment.marker, Some(marker) if marker.kind == MarkerKind::Closure)
+}
+
+/// Whether a segment can be abbreviated, which excludes markers such as `{{closure}}`.
+fn is_abbreviable(ident: &str) -> bool {
+    !ident.starts_with('{')
+}
+
+/// Writes the first character of each `_` separated word of `ident`,
+/// so that `bevy_render` becomes `b_r`.
+fn write_initials(w: &mut dyn Write, ident: &str) -> fmt::Result {
+    let mut words = ident.split('_').filter_map(|word| word.chars().next());
+    if let Some(initial) = words.next() {
+        w.write_char(initial)?;
+    }
+    for initial in words {
+        w.write_char('_')?;
+        w.write_char(initial)?;
+    }
+    Ok(())
+}
@@ -1,3 +1,9 @@
+use crate::{
+    Bindings, Bounds, Breakdown, CapacityError, Chars, Colored, DisplayWith, DotLabel, GenericArgs,
+    Html, Lifetimes, Markdown, NameKind, ParseError, Segments, TokenKind, TupleElements,
+    TypeNameTokens, TypedGenericArgs, WithContext, WithoutGenerics,
+};
+
 /// Lazily shortens a type name to remove all module paths.
 ///
 /// The short name of a type is its full name as returned by
@@ -5,10 +11,32 @@
 /// example, the short name of `alloc::vec::Vec<core::option::Option<u32>>`
 /// would be `Vec<Option<u32>>`.
 ///
-/// Shortening is performed lazily without allocation.
+/// Shortening is performed lazily without allocation, in a single pass over the
+/// name. Displaying, comparing and searching names by the default rules takes time
+/// linear in the length of the name whatever the name is, which makes it safe to
+/// use on untrusted input, such as demangled symbols. Views which lay the name out,
+/// such as [`wrapped`](Self::wrapped), can take longer on pathological names.
+#[cfg_attr(
+    feature = "std",
+    doc = r#" The exception is when a configuration was set with [`set_default_config`](crate::set_default_config), as names are then parsed to follow it."#
+)]
+#[cfg_attr(
+    feature = "alloc",
+    doc = r#" To get a [`String`](alloc::string::String) from this type, use [`String::from`](alloc::string::String::from), which avoids growing the string as it is written, or the [`to_string`](`alloc::string::ToString::to_string`) method."#
+)]
+///
+/// Displaying a name with the alternate flag, as in `{:#}`, writes the original
+/// name instead, which is useful for searching for it.
+///
+/// Names compare, order and hash by their shortened names as they are displayed,
+/// so they can be used as map keys which group names shortening the same way,
+/// or sorted as they are displayed.
 #[cfg_attr(
     feature = "alloc",
-    doc = r#" To get a [`String`] from this type, use the [`to_string`](`alloc::string::ToString::to_string`) method."#
+    doc = r#"
+Names built at runtime, such as those read from a file, can be borrowed from
+wherever they are stored, including a [`Cow`](alloc::borrow::Cow), or kept in a
+[`ShortNameOwned`](crate::ShortNameOwned), which owns its name."#
 )]
 ///
 /// # Examples
@@ -31,6 +59,91 @@ impl ShortName<'static> {
     pub fn of<T: ?Sized>() -> Self {
         Self(core::any::type_name::<T>())
     }
+
+    /// Gets a shortened version of the name of the type of `value`, for values
+    /// whose types cannot be named, such as closures.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// fn move_player() {}
+    ///
+    /// assert_eq!(ShortName::of_val(&move_player).to_string(), "move_player");
+    /// assert_eq!(ShortName::of_val(&1.0_f32).to_string(), "f32");
+    ///
+    /// let label = ShortName::of_val(&|| {}).to_string();
+    /// assert!(label.ends_with("{{closure}}"));
+    /// ```
+    pub fn of_val<T: ?Sized>(value: &T) -> Self {
+        Self(core::any::type_name_of_val(value))
+    }
+
+    /// Gets the shortened name of the type `T` as a string, which is only built the
+    /// first time it is asked for and then kept for the rest of the process.
+    ///
+    /// This suits names which are shown over and over, such as in diagnostics
+    /// written every frame. After the first call on a thread, getting the name
+    /// takes no lock, only a lookup in a table kept by each thread.
+    ///
+    /// The name is kept as first shortened on any thread, following the default
+    /// configuration set at that point, if any, for the rest of the process. It
+    /// does not follow later calls to [`set_default_config`](crate::set_default_config).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let name: &'static str = ShortName::cached::<Vec<Option<u32>>>();
+    /// assert_eq!(name, "Vec<Option<u32>>");
+    /// assert!(core::ptr::eq(name, ShortName::cached::<Vec<Option<u32>>>()));
+    ///
+    /// disqualified::set_default_config(ShortNameConfig::new().max_generic_depth(0));
+    /// let on_other_thread = std::thread::spawn(ShortName::cached::<Vec<Option<u32>>>);
+    /// assert!(core::ptr::eq(name, on_other_thread.join().unwrap()));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn cached<T: ?Sized>() -> &'static str {
+        use alloc::collections::BTreeMap;
+        use core::cell::RefCell;
+        use std::{
+            collections::HashMap,
+            sync::{PoisonError, RwLock},
+        };
+
+        /// The shortened names of the types seen on any thread, which each thread
+        /// copies into its own table the first time it asks for them.
+        static SHORTENED: RwLock<BTreeMap<(usize, usize), &'static str>> =
+            RwLock::new(BTreeMap::new());
+
+        std::thread_local! {
+            /// The shortened names of the types seen on this thread, by the address
+            /// and length of their full names, which are cheaper to hash than the
+            /// names themselves.
+            static CACHE: RefCell<HashMap<(usize, usize), &'static str, AddressHasher>> =
+                RefCell::new(HashMap::default());
+        }
+
+        let name = core::any::type_name::<T>();
+        let key = (name.as_ptr() as usize, name.len());
+        if let Some(short) = CACHE.with_borrow(|cache| cache.get(&key).copied()) {
+            return short;
+        }
+        let shortened = SHORTENED
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+            .copied();
+        let short = shortened.unwrap_or_else(|| {
+            let mut shortened = SHORTENED.write().unwrap_or_else(PoisonError::into_inner);
+            // Another thread may have shortened the name since it was looked up.
+            *shortened
+                .entry(key)
+                .or_insert_with(|| ShortName(name).into_static())
+        });
+        CACHE.with_borrow_mut(|cache| cache.insert(key, short));
+        short
+    }
 }
 
 impl<'a> ShortName<'a> {
@@ -38,6 +151,1073 @@ impl<'a> ShortName<'a> {
     pub const fn original(&self) -> &'a str {
         self.0
     }
+
+    /// Writes the shortened name to `w`, as the [`Display`](core::fmt::Display)
+    /// implementation does.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let mut buffer = String::from("system: ");
+    /// ShortName("my_game::move_player").write_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, "system: move_player");
+    /// ```
+    pub fn write_to(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        #[cfg(feature = "std")]
+        if let Some(config) = crate::config::default_config() {
+            return self.with_config(&config).write_to(w);
+        }
+        self.write_short(w)
+    }
+
+    /// Replaces the contents of `buffer` with the shortened name.
+    ///
+    /// This reuses the allocation of `buffer`, which helps when shortening many names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let mut buffer = String::new();
+    /// for name in ["my_game::move_player", "my_game::jump"] {
+    ///     ShortName(name).shorten_into(&mut buffer);
+    ///     println!("{buffer}");
+    /// }
+    /// assert_eq!(buffer, "jump");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn shorten_into(&self, buffer: &mut alloc::string::String) {
+        buffer.clear();
+        // Writing to a `String` cannot fail.
+        let _ = self.write_to(buffer);
+    }
+
+    /// Writes the shortened name to the start of `buffer`, returning it as a [`str`].
+    ///
+    /// This needs no allocator. If `buffer` is too small, the error reports how many
+    /// bytes are needed, and the contents of `buffer` are unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let mut buffer = [0; 16];
+    /// let name = ShortName("alloc::vec::Vec<my_game::Player>");
+    /// assert_eq!(name.shorten_into_slice(&mut buffer), Ok("Vec<Player>"));
+    ///
+    /// let error = name.shorten_into_slice(&mut buffer[..4]).unwrap_err();
+    /// assert_eq!(error.required, 11);
+    /// ```
+    pub fn shorten_into_slice<'b>(&self, buffer: &'b mut [u8]) -> Result<&'b str, CapacityError> {
+        let mut writer = crate::display::SliceWriter {
+            buffer,
+            len: 0,
+            overflowed: false,
+        };
+        // Writing to a slice never fails, it only stops copying once it is full.
+        let _ = self.write_to(&mut writer);
+        let crate::display::SliceWriter {
+            buffer,
+            len,
+            overflowed,
+        } = writer;
+        if overflowed {
+            return Err(CapacityError {
+                required: len,
+                available: buffer.len(),
+            });
+        }
+        // Only whole `str`s were copied, so this is valid UTF-8.
+        Ok(core::str::from_utf8(&buffer[..len]).unwrap_or_default())
+    }
+
+    /// Gets the shortened name, borrowed from the original name where possible.
+    ///
+    /// Names are borrowed when the shortened name is the end of the original one,
+    /// as for `Vec<u8>` in `alloc::vec::Vec<u8>`, and allocated otherwise, as for
+    /// `Vec<Player>` in `alloc::vec::Vec<my_game::Player>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// # use std::borrow::Cow;
+    /// let name = ShortName("alloc::vec::Vec<u8>");
+    /// assert!(matches!(name.to_cow(), Cow::Borrowed("Vec<u8>")));
+    ///
+    /// let name = ShortName("alloc::vec::Vec<my_game::Player>");
+    /// assert!(matches!(name.to_cow(), Cow::Owned(owned) if owned == "Vec<Player>"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_cow(&self) -> alloc::borrow::Cow<'a, str> {
+        let len = self.shortened_len();
+        if let Some(suffix) = self
+            .0
+            .len()
+            .checked_sub(len)
+            .and_then(|start| self.0.get(start..))
+        {
+            let mut compare = crate::display::Compare {
+                expected: suffix,
+                offset: 0,
+            };
+            if self.write_to(&mut compare).is_ok() && compare.offset == suffix.len() {
+                return alloc::borrow::Cow::Borrowed(suffix);
+            }
+        }
+        alloc::borrow::Cow::Owned((*self).into())
+    }
+
+    /// Shortens the name once, keeping the result for displaying, measuring or
+    /// comparing it many times, such as every frame of an immediate-mode UI.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("my_game::ui::health_bar<my_game::Player>").analyzed();
+    /// for _frame in 0..3 {
+    ///     assert_eq!(format!("{name}"), "health_bar<Player>");
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn analyzed(&self) -> crate::AnalyzedName<'a> {
+        crate::AnalyzedName::new(*self)
+    }
+
+    /// Whether shortening leaves the name as it is, such as for `u32`, `Vec<u8>` or
+    /// `Option::None`, so that it can be used without going through [`ShortName`].
+    ///
+    /// Names without any `::` are checked without shortening them at all.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// assert!(ShortName("Vec<u8>").is_already_short());
+    /// # #[cfg(not(disqualified_minimal))]
+    /// assert!(ShortName("Option::None").is_already_short());
+    /// assert!(!ShortName("alloc::vec::Vec<u8>").is_already_short());
+    /// ```
+    pub fn is_already_short(&self) -> bool {
+        #[cfg(feature = "std")]
+        let has_default_config = crate::config::default_config().is_some();
+        #[cfg(not(feature = "std"))]
+        let has_default_config = false;
+        if !has_default_config && !self.0.contains("::") {
+            return true;
+        }
+
+        let mut compare = crate::display::Compare {
+            expected: self.0,
+            offset: 0,
+        };
+        self.write_to(&mut compare).is_ok() && compare.offset == self.0.len()
+    }
+
+    /// Gets an iterator over the [`char`]s of the shortened name as it is displayed,
+    /// which shortens the name as it goes rather than all at once unless a default
+    /// configuration is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("alloc::vec::Vec<u8>");
+    /// assert!(name.chars().eq("Vec<u8>".chars()));
+    /// assert_eq!(name.chars().filter(char::is_ascii_uppercase).count(), 1);
+    /// ```
+    pub fn chars(&self) -> Chars<'a> {
+        Chars::new(*self)
+    }
+
+    /// Whether the shortened name, as it is displayed, starts with `prefix`,
+    /// checked without allocating unless a default configuration is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("bevy_asset::assets::Assets<bevy_image::Image>");
+    /// assert!(name.starts_with("Assets<Im"));
+    /// assert!(!name.starts_with("bevy_asset"));
+    /// ```
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        let mut rest = prefix.as_bytes();
+        for chunk in self.output().chunks() {
+            let chunk = chunk.as_bytes();
+            if rest.len() <= chunk.len() {
+                return chunk.starts_with(rest);
+            }
+            let Some(tail) = rest.strip_prefix(chunk) else {
+                return false;
+            };
+            rest = tail;
+        }
+        rest.is_empty()
+    }
+
+    /// Whether the shortened name, as it is displayed, ends with `suffix`,
+    /// checked without allocating unless a default configuration is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// assert!(ShortName("bevy_ui::UiPlugin").ends_with("Plugin"));
+    /// assert!(ShortName("bevy_ui::UiPlugin").ends_with("UiPlugin"));
+    /// assert!(!ShortName("bevy_ui::UiPlugin").ends_with("ui::UiPlugin"));
+    /// ```
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        let output = self.output();
+        let len: usize = output.chunks().map(str::len).sum();
+        let Some(mut skip) = len.checked_sub(suffix.len()) else {
+            return false;
+        };
+        let mut rest = suffix.as_bytes();
+        for chunk in output.chunks() {
+            let chunk = chunk.as_bytes();
+            if skip >= chunk.len() {
+                skip -= chunk.len();
+                continue;
+            }
+            let Some(tail) = rest.strip_prefix(&chunk[skip..]) else {
+                return false;
+            };
+            rest = tail;
+            skip = 0;
+        }
+        rest.is_empty()
+    }
+
+    /// Whether the shortened name, as it is displayed, contains `needle`, searched
+    /// for as the name is shortened, without allocating unless a default
+    /// configuration is set.
+    ///
+    /// This suits filtering names by what is displayed, as in a search box. The
+    /// time taken is at worst proportional to the length of the name times the
+    /// length of `needle`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("bevy_asset::assets::Assets<bevy_image::Image>");
+    /// assert!(name.contains("ts<Im"));
+    /// assert!(!name.contains("bevy"));
+    /// ```
+    pub fn contains(&self, needle: &str) -> bool {
+        /// Whether `needle` is found at the start of `current` and the chunks after it.
+        fn matches_at<'c>(
+            mut current: &'c [u8],
+            mut chunks: OutputChunks<'c>,
+            mut needle: &[u8],
+        ) -> bool {
+            loop {
+                let len = current.len().min(needle.len());
+                if current[..len] != needle[..len] {
+                    return false;
+                }
+                needle = &needle[len..];
+                if needle.is_empty() {
+                    return true;
+                }
+                match chunks.next() {
+                    Some(chunk) => current = chunk.as_bytes(),
+                    None => return false,
+                }
+            }
+        }
+
+        let needle = needle.as_bytes();
+        let Some(&first) = needle.first() else {
+            return true;
+        };
+        let output = self.output();
+        let mut chunks = output.chunks();
+        while let Some(chunk) = chunks.next() {
+            let mut current = chunk.as_bytes();
+            while let Some(start) = current.iter().position(|&byte| byte == first) {
+                current = &current[start..];
+                if matches_at(current, chunks.clone(), needle) {
+                    return true;
+                }
+                current = &current[1..];
+            }
+        }
+        false
+    }
+
+    /// Copies the original name into a [`ShortNameOwned`](crate::ShortNameOwned),
+    /// which has no lifetime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = String::from("my_game::Player");
+    /// let owned = ShortName(&name).to_owned_name();
+    /// drop(name);
+    /// assert_eq!(owned.to_string(), "Player");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_owned_name(&self) -> crate::ShortNameOwned {
+        crate::ShortNameOwned::new(self.0)
+    }
+
+    /// Gets the shortened name as a [`Box<str>`](alloc::boxed::Box), which takes a
+    /// little less memory to keep than a [`String`](alloc::string::String) as it
+    /// has no separate capacity.
+    ///
+    /// The name is sized exactly as it is built, so this does not reallocate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name: Box<str> = ShortName("alloc::vec::Vec<my_game::Player>").boxed();
+    /// assert_eq!(&*name, "Vec<Player>");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn boxed(&self) -> alloc::boxed::Box<str> {
+        alloc::string::String::from(*self).into_boxed_str()
+    }
+
+    /// Gets the shortened name as a string which lives for the rest of the process,
+    /// for APIs which only take a `&'static str`.
+    ///
+    /// Each distinct shortened name is only leaked the first time it is asked for,
+    /// and is shared with [`cached`](Self::cached), so calling this over and over
+    /// for the same names does not keep using more memory.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = String::from("my_game::Player");
+    /// let short: &'static str = ShortName(&name).into_static();
+    /// drop(name);
+    /// assert_eq!(short, "Player");
+    /// assert!(core::ptr::eq(short, ShortName("other_game::Player").into_static()));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn into_static(self) -> &'static str {
+        use alloc::{collections::BTreeSet, string::String};
+        use std::sync::{PoisonError, RwLock};
+
+        static NAMES: RwLock<BTreeSet<&'static str>> = RwLock::new(BTreeSet::new());
+
+        let short = String::from(self);
+        if let Some(&name) = NAMES
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(short.as_str())
+        {
+            return name;
+        }
+        let mut names = NAMES.write().unwrap_or_else(PoisonError::into_inner);
+        // Another thread may have added the name since it was looked up.
+        if let Some(&name) = names.get(short.as_str()) {
+            return name;
+        }
+        let name = short.leak();
+        names.insert(name);
+        name
+    }
+
+    /// Gets the length in bytes of the shortened name, without allocating it.
+    ///
+    /// The length is exact, so it can be used to reserve room for the name up
+    /// front, such as before writing it into a buffer with `shorten_into`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// assert_eq!(ShortName("my_game::Größe<u8>").shortened_len(), 11);
+    /// # #[cfg(feature = "alloc")] {
+    ///
+    /// let name = ShortName("alloc::vec::Vec<my_game::Player>");
+    /// let mut buffer = String::new();
+    /// buffer.reserve_exact(name.shortened_len());
+    /// let capacity = buffer.capacity();
+    /// name.shorten_into(&mut buffer);
+    /// assert_eq!(buffer.capacity(), capacity);
+    /// # }
+    /// ```
+    #[doc(alias = "estimate_shortened_capacity")]
+    pub fn shortened_len(&self) -> usize {
+        self.measure().bytes
+    }
+
+    /// Gets the length in [`char`]s of the shortened name, without allocating it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// assert_eq!(ShortName("my_game::Größe<u8>").shortened_char_count(), 9);
+    /// ```
+    pub fn shortened_char_count(&self) -> usize {
+        self.measure().chars
+    }
+
+    fn measure(&self) -> crate::display::Measure {
+        let mut measure = crate::display::Measure::default();
+        // Measuring cannot fail, as nothing is written anywhere.
+        let _ = self.write_to(&mut measure);
+        measure
+    }
+
+    /// Gets an iterator over the segments of the outermost path of the original name,
+    /// skipping any generic arguments.
+    ///
+    /// If the name does not start with a path, such as for a tuple or reference,
+    /// the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("bevy_render::camera::extract_cameras<bevy_render::Camera3d>");
+    /// assert!(name.segments().eq(["bevy_render", "camera", "extract_cameras"]));
+    /// ```
+    pub fn segments(&self) -> Segments<'a> {
+        Segments::new(self.0)
+    }
+
+    /// Gets an iterator over the top-level generic arguments of the outermost path
+    /// of the original name.
+    ///
+    /// Nested generics, tuples and other bracketed types are returned whole.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("hashbrown::HashMap<u32, alloc::vec::Vec<(u8, u16)>>");
+    /// let args: Vec<_> = name.generic_args().map(|arg| arg.to_string()).collect();
+    /// assert_eq!(args, ["u32", "Vec<(u8, u16)>"]);
+    /// ```
+    pub fn generic_args(&self) -> GenericArgs<'a> {
+        GenericArgs::new(self.0)
+    }
+
+    /// Gets an iterator over the same arguments as [`generic_args`](Self::generic_args),
+    /// classified as types, const values, lifetimes or associated type bindings.
+    ///
+    /// For an array or slice, the element type and length are returned instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{GenericArgument, ShortName};
+    /// let name = ShortName("arrayvec::ArrayVec<my_game::Item, 16>");
+    /// let mut args = name.typed_generic_args();
+    /// assert!(matches!(args.next(), Some(GenericArgument::Type(ty)) if ty.to_string() == "Item"));
+    /// assert!(matches!(args.next(), Some(GenericArgument::Const("16"))));
+    ///
+    /// let mut args = ShortName("[i32; 3]").typed_generic_args();
+    /// assert!(matches!(args.next(), Some(GenericArgument::Type(_))));
+    /// assert!(matches!(args.next(), Some(GenericArgument::Const("3"))));
+    /// ```
+    pub fn typed_generic_args(&self) -> TypedGenericArgs<'a> {
+        TypedGenericArgs::new(self.0)
+    }
+
+    /// Gets an iterator over the bounds of the original name, if it is a trait object
+    /// or `impl Trait` type, such as `dyn Trait + Send + Sync`.
+    ///
+    /// Trait objects nested inside another type, as in `Box<dyn Trait + Send>`, are
+    /// not searched; use [`generic_args`](Self::generic_args) to reach them first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("dyn my_game::Ability + core::marker::Send + core::marker::Sync");
+    /// let bounds: Vec<_> = name.bounds().map(|bound| bound.to_string()).collect();
+    /// assert_eq!(bounds, ["Ability", "Send", "Sync"]);
+    /// ```
+    pub fn bounds(&self) -> Bounds<'a> {
+        Bounds::new(self.0)
+    }
+
+    /// Gets an iterator over the lifetimes in the original name, such as `'a`,
+    /// `'static` and `'_`, in the order they appear.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("bevy_ecs::system::Res<'static, my_game::Score>");
+    /// assert!(name.lifetimes().eq(["'static"]));
+    /// assert_eq!(ShortName("my_game::Score").lifetimes().count(), 0);
+    /// ```
+    pub fn lifetimes(&self) -> Lifetimes<'a> {
+        Lifetimes::new(self.0)
+    }
+
+    /// Gets an iterator over the associated type bindings in the original name, such
+    /// as `Item = u32` in `dyn Iterator<Item = u32>`.
+    ///
+    /// Each binding is returned as its name and bound type. Bindings are found
+    /// at any depth, in the order they appear, including those nested inside the
+    /// types of other bindings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("core::pin::Pin<alloc::boxed::Box<dyn core::future::Future<Output = my_game::Level>>>");
+    /// let (binding, ty) = name.bindings().next().unwrap();
+    /// assert_eq!(binding, "Output");
+    /// assert_eq!(ty.to_string(), "Level");
+    /// ```
+    pub fn bindings(&self) -> Bindings<'a> {
+        Bindings::new(self.0)
+    }
+
+    /// Gets an iterator over the elements of the original name, if it is a tuple,
+    /// or over its arguments, if it is a call-like name such as `Pipe(a::b, c::d)`.
+    ///
+    /// Systems combined in a tuple or combinator can then be shortened one at a
+    /// time. Names of any other shape have no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("bevy_ecs::system::Pipe(my_game::load, my_game::check<u8>)");
+    /// let elements: Vec<_> = name.split_tuple_elements().map(|e| e.to_string()).collect();
+    /// assert_eq!(elements, ["load", "check<u8>"]);
+    /// ```
+    pub fn split_tuple_elements(&self) -> TupleElements<'a> {
+        TupleElements::new(self.0)
+    }
+
+    /// Gets the final identifier of the outermost path of the original name, without
+    /// any module path or generic arguments.
+    ///
+    /// Returns [`None`] if the name does not start with a path, such as for a tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("bevy_asset::assets::Assets<bevy_image::Image>");
+    /// assert_eq!(name.ident(), Some("Assets"));
+    /// ```
+    pub fn ident(&self) -> Option<&'a str> {
+        self.segments().last()
+    }
+
+    /// Gets a view of the name which displays following `config`, rather than the
+    /// default shortening rules.
+    ///
+    /// Names which cannot be [parsed](crate::parse::parse) fall back to being
+    /// shortened by the default rules, or displayed in full if paths are not to be
+    /// shortened. This includes names nested more than 128 deep, so that the time
+    /// taken stays linear in the length of the name. Only
+    /// [`max_len`](crate::ShortNameConfig::max_len) and
+    /// [`line_width`](crate::ShortNameConfig::line_width) still apply to these
+    /// names, and options such as
+    /// [`max_generic_depth`](crate::ShortNameConfig::max_generic_depth) are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ShortName, ShortNameConfig};
+    /// let config = ShortNameConfig::new();
+    /// let name = ShortName("bevy_asset::Assets<bevy_image::Image>");
+    /// assert_eq!(name.with_config(&config).to_string(), "Assets<Image>");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub const fn with_config<'c>(
+        &self,
+        config: &'c crate::ShortNameConfig,
+    ) -> crate::WithConfig<'c, 'a> {
+        crate::WithConfig {
+            name: *self,
+            config,
+        }
+    }
+
+    /// Gets a view of the shortened name which displays without any generic arguments,
+    /// such as `extract_cameras` for `extract_cameras<Camera3d>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("bevy_render::camera::extract_cameras<bevy_render::Camera3d>");
+    /// assert_eq!(name.without_generics().to_string(), "extract_cameras");
+    /// ```
+    pub const fn without_generics(&self) -> WithoutGenerics<'a> {
+        WithoutGenerics(*self)
+    }
+
+    /// Gets a view of the shortened name which displays followed by the module the
+    /// item is in, as in `Baz (foo::bar)` for `foo::bar::Baz`.
+    ///
+    /// The module is the [`module_path`](Self::module_path), and is left out when
+    /// there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("bevy_asset::assets::Assets<bevy_image::Image>");
+    /// assert_eq!(name.with_context().to_string(), "Assets<Image> (bevy_asset::assets)");
+    /// assert_eq!(ShortName("u32").with_context().to_string(), "u32");
+    /// ```
+    pub const fn with_context(&self) -> WithContext<'a> {
+        WithContext(*self)
+    }
+
+    /// Gets a view of the name which displays the parts it is made of, for
+    /// explaining how it is shortened.
+    ///
+    /// The crate, the module within the crate, the [`ident`](Self::ident) and the
+    /// shortened [`generic_args`](Self::generic_args) are written as `key=value`
+    /// pairs, leaving out any the name does not have.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("bevy_render::camera::extract_cameras<bevy_render::camera::Camera3d>");
+    /// assert_eq!(
+    ///     name.breakdown().to_string(),
+    ///     "crate=bevy_render module=camera ident=extract_cameras generics=[Camera3d]"
+    /// );
+    /// assert_eq!(ShortName("u32").breakdown().to_string(), "ident=u32");
+    /// ```
+    pub const fn breakdown(&self) -> Breakdown<'a> {
+        Breakdown(*self)
+    }
+
+    /// Gets a view of the shortened name which displays as HTML, with identifiers,
+    /// generic brackets and other punctuation wrapped in `<span>` elements of the
+    /// classes `ident`, `generic` and `punct` respectively.
+    ///
+    /// A width or precision pads or cuts the text of the name, not counting the
+    /// markup around it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("alloc::vec::Vec<u8>");
+    /// assert_eq!(
+    ///     name.html().to_string(),
+    ///     r#"<span class="ident">Vec</span><span class="generic">&lt;</span><span class="ident">u8</span><span class="generic">&gt;</span>"#
+    /// );
+    /// ```
+    pub const fn html(&self) -> Html<'a> {
+        Html(*self)
+    }
+
+    /// Gets a view of the shortened name which displays as a Markdown code span,
+    /// so that generic brackets are not mistaken for HTML tags.
+    ///
+    /// A width or precision pads or cuts the name inside the code span, not
+    /// counting the backticks around it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("alloc::vec::Vec<core::option::Option<u32>>");
+    /// assert_eq!(name.markdown().to_string(), "`Vec<Option<u32>>`");
+    /// ```
+    pub const fn markdown(&self) -> Markdown<'a> {
+        Markdown(*self)
+    }
+
+    /// Gets a view of the shortened name which displays through `style`, a callback
+    /// called with each [`Piece`](crate::Piece) of it in turn.
+    ///
+    /// This allows names to be colored, linked or emphasized in any way. A width or
+    /// precision pads or cuts the text of the name, not counting anything `style`
+    /// adds around it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{PieceKind, ShortName};
+    /// let name = ShortName("alloc::vec::Vec<u8>");
+    /// let bold = name.display_with(|piece, f| match piece.kind {
+    ///     PieceKind::Ident => write!(f, "**{}**", piece.text),
+    ///     _ => f.write_str(piece.text),
+    /// });
+    /// assert_eq!(bold.to_string(), "**Vec**<**u8**>");
+    /// ```
+    pub const fn display_with<F>(&self, style: F) -> DisplayWith<'a, F>
+    where
+        F: Fn(crate::Piece<'_>, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+    {
+        DisplayWith { name: *self, style }
+    }
+
+    /// Gets a view of the shortened name which displays colored with ANSI escape
+    /// codes, for printing to a terminal.
+    ///
+    /// Coloring can be turned off with [`Colored::enabled`], or
+    /// `Colored::auto` when the `std` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("alloc::vec::Vec<u8>");
+    /// assert_eq!(
+    ///     name.colored().to_string(),
+    ///     "\x1b[36mVec\x1b[0m\x1b[2m<\x1b[0m\x1b[36mu8\x1b[0m\x1b[2m>\x1b[0m"
+    /// );
+    /// assert_eq!(name.colored().enabled(false).to_string(), "Vec<u8>");
+    /// ```
+    pub const fn colored(&self) -> Colored<'a> {
+        Colored {
+            name: *self,
+            enabled: true,
+        }
+    }
+
+    /// Gets a view of the shortened name which displays escaped for use in a
+    /// Graphviz label.
+    ///
+    /// By default the characters with a special meaning in quoted strings and
+    /// record labels are escaped with backslashes. Use [`DotLabel::html`] to escape
+    /// the name for HTML-like labels instead. A width or precision pads or cuts
+    /// the name before it is escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("alloc::vec::Vec<u8>");
+    /// assert_eq!(name.dot_label().to_string(), r"Vec\<u8\>");
+    /// assert_eq!(name.dot_label().html(true).to_string(), "Vec&lt;u8&gt;");
+    /// ```
+    pub const fn dot_label(&self) -> DotLabel<'a> {
+        DotLabel {
+            name: *self,
+            html: false,
+        }
+    }
+
+    /// Gets a view of the shortened name which displays wrapped onto more lines
+    /// when it is longer than `width` columns.
+    ///
+    /// Lines are only broken after the commas directly inside the outermost generic
+    /// arguments or tuples, and continuation lines are aligned with the start of
+    /// the group they continue.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("std::collections::hash::map::HashMap<my_game::PlayerId, alloc::vec::Vec<my_game::Item>>");
+    /// assert_eq!(
+    ///     name.wrapped(24).to_string(),
+    ///     "HashMap<PlayerId,\n        Vec<Item>>"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub const fn wrapped(&self, width: usize) -> crate::Wrapped<'a> {
+        crate::Wrapped { name: *self, width }
+    }
+
+    /// Gets a view of the shortened name which displays as a label for people to
+    /// read, such as in an editor, rather than as Rust syntax.
+    ///
+    /// Identifiers are split into capitalized words at underscores and changes of
+    /// case, and generic arguments are put in parentheses. Runs of uppercase letters
+    /// are kept together, and other acronyms can be given with [`Label::acronyms`](crate::Label::acronyms).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("bevy_render::camera::extract_cameras<bevy_render::camera::Camera3d>");
+    /// assert_eq!(name.label().to_string(), "Extract Cameras (Camera 3d)");
+    /// assert_eq!(ShortName("my_game::HUDLayout").label().to_string(), "HUD Layout");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub const fn label(&self) -> crate::Label<'a, 'static> {
+        crate::Label {
+            name: *self,
+            acronyms: &[],
+        }
+    }
+
+    /// Gets a view of the shortened name which displays each segment of its paths
+    /// as changed by `map`, such as to rename or redact some of them.
+    ///
+    /// Segments are identifiers and markers such as `{{closure}}`, but not keywords
+    /// such as `dyn` or `mut`, nor lifetimes or literals. Everything else is written
+    /// as shortened.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// use std::borrow::Cow;
+    ///
+    /// let name = ShortName("my_game::Secret<&mut dyn my_game::Plan>");
+    /// let redacted = name.map_segments(|segment| match segment {
+    ///     "Secret" | "Plan" => Cow::Owned("*".repeat(segment.len())),
+    ///     _ => Cow::Borrowed(segment),
+    /// });
+    /// assert_eq!(redacted.to_string(), "******<&mut dyn ****>");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub const fn map_segments<F>(&self, map: F) -> crate::MapSegments<'a, F>
+    where
+        F: Fn(&str) -> alloc::borrow::Cow<'_, str>,
+    {
+        crate::MapSegments { name: *self, map }
+    }
+
+    /// Gets the maximum depth to which generic arguments, tuples, arrays and other
+    /// bracketed groups are nested in the original name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// assert_eq!(ShortName("u32").nesting_depth(), 0);
+    /// assert_eq!(ShortName("alloc::vec::Vec<(u8, u16)>").nesting_depth(), 2);
+    /// ```
+    pub fn nesting_depth(&self) -> usize {
+        let mut depth = 0_usize;
+        let mut max_depth = 0;
+        for token in TypeNameTokens::new(self.0) {
+            match token.kind {
+                TokenKind::Lt
+                | TokenKind::OpenParen
+                | TokenKind::OpenBracket
+                | TokenKind::OpenBrace => {
+                    depth += 1;
+                    max_depth = max_depth.max(depth);
+                }
+                TokenKind::Gt
+                | TokenKind::CloseParen
+                | TokenKind::CloseBracket
+                | TokenKind::CloseBrace => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+        max_depth
+    }
+
+    /// Guesses what sort of item the original name refers to, such as a type,
+    /// function or closure, from its structure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{NameKind, ShortName};
+    /// assert_eq!(ShortName("my_game::move_player").kind(), NameKind::Function);
+    /// assert_eq!(ShortName("my_game::setup::{{closure}}").kind(), NameKind::Closure);
+    /// assert_eq!(ShortName("my_game::Player").kind(), NameKind::Type);
+    /// ```
+    pub fn kind(&self) -> NameKind {
+        crate::kind::classify(*self)
+    }
+
+    /// Whether the original name is one of Rust's primitive types, such as `u32`,
+    /// `bool`, `str` or `()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// assert!(ShortName("u32").is_primitive());
+    /// assert!(!ShortName("alloc::string::String").is_primitive());
+    /// ```
+    pub fn is_primitive(&self) -> bool {
+        crate::kind::is_primitive(self.0.trim())
+    }
+
+    /// Whether the outermost path of the original name is rooted in the standard
+    /// library, that is in `core`, `alloc` or `std`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// assert!(ShortName("alloc::vec::Vec<my_game::Player>").is_std_type());
+    /// assert!(!ShortName("my_game::Player").is_std_type());
+    /// ```
+    pub fn is_std_type(&self) -> bool {
+        self.crate_name().is_some_and(crate::kind::is_std_crate)
+    }
+
+    /// Checks that the original name is well-formed.
+    ///
+    /// This catches garbage such as unbalanced brackets or a `::` which does not join
+    /// two path segments, reporting the byte offset of the problem. Names returned by
+    /// [`core::any::type_name`] are always valid. Validation does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::{ParseErrorKind, ShortName};
+    /// assert!(ShortName("alloc::vec::Vec<u32>").validate().is_ok());
+    ///
+    /// let error = ShortName("alloc::vec::Vec<u32").validate().unwrap_err();
+    /// assert_eq!(error.offset, 15);
+    /// assert_eq!(error.kind, ParseErrorKind::UnclosedDelimiter);
+    /// ```
+    pub fn validate(&self) -> Result<(), ParseError> {
+        crate::validate::validate(self.0)
+    }
+
+    /// Peels off layers of wrapper types, such as `Arc` or `Box`, returning the
+    /// name of the innermost type which is not a wrapper.
+    ///
+    /// Each wrapper is either a full path, such as `alloc::sync::Arc`, or a bare
+    /// identifier, such as `Arc`, which matches a wrapper in any module. Only the
+    /// first generic argument of a wrapper is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("alloc::sync::Arc<std::sync::Mutex<my_game::GameState>>");
+    /// let inner = name.strip_wrappers(&["alloc::sync::Arc", "Mutex"]);
+    /// assert_eq!(inner.to_string(), "GameState");
+    /// ```
+    pub fn strip_wrappers(&self, wrappers: &[&str]) -> ShortName<'a> {
+        let mut name = *self;
+        loop {
+            let Some(base) = name.segments().generic_base() else {
+                return name;
+            };
+            let ident = name.ident().unwrap_or(base);
+            let is_wrapper = wrappers
+                .iter()
+                .any(|&wrapper| wrapper == base || (!wrapper.contains("::") && wrapper == ident));
+            match name.generic_args().next() {
+                Some(inner) if is_wrapper => name = inner,
+                _ => return name,
+            }
+        }
+    }
+
+    /// Gets the crate at the root of the outermost path of the original name.
+    ///
+    /// Returns [`None`] if the path has only a single segment, such as `u32`, or if
+    /// the name does not start with a path rooted in a crate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("bevy_render::camera::Camera<alloc::string::String>");
+    /// assert_eq!(name.crate_name(), Some("bevy_render"));
+    /// assert_eq!(ShortName("u32").crate_name(), None);
+    /// ```
+    pub fn crate_name(&self) -> Option<&'a str> {
+        let mut segments = self.segments();
+        if segments.is_qualified() {
+            return None;
+        }
+        let first = segments.next()?;
+        segments.next().map(|_| first)
+    }
+
+    /// Gets the module containing the item named by the outermost path of the
+    /// original name, such as `foo::bar` for `foo::bar::Baz<T>`.
+    ///
+    /// As with shortening, a segment starting with an uppercase letter is assumed to
+    /// be a type rather than a module, so the module path of
+    /// `bevy_render::RenderSet::Prepare` is `bevy_render`. Returns [`None`] if the
+    /// item is not inside a module.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("bevy_asset::assets::Assets<bevy_image::Image>");
+    /// assert_eq!(name.module_path(), Some("bevy_asset::assets"));
+    /// ```
+    pub fn module_path(&self) -> Option<&'a str> {
+        let mut segments = self.segments();
+        if segments.is_qualified() {
+            return None;
+        }
+
+        let mut end = None;
+        while let Some((offset, segment)) = segments.next_spanned() {
+            if segments.is_done() || segments.had_args() || segment.starts_with(char::is_uppercase)
+            {
+                break;
+            }
+            end = Some(offset + segment.len());
+        }
+        end.map(|end| &self.0[..end])
+    }
+
+    /// Gets the longest module path shared by the [`module_path`](Self::module_path)s
+    /// of this name and `other`, such as `bevy_render::camera` for
+    /// `bevy_render::camera::Camera` and `bevy_render::camera::projection::Projection`.
+    ///
+    /// Returns [`None`] if the names share no module at all.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let camera = ShortName("bevy_render::camera::Camera");
+    /// let projection = ShortName("bevy_render::camera::projection::Projection");
+    /// assert_eq!(camera.common_ancestor_module(projection), Some("bevy_render::camera"));
+    /// assert_eq!(camera.common_ancestor_module(ShortName("bevy_pbr::Mesh3d")), None);
+    /// ```
+    pub fn common_ancestor_module(&self, other: ShortName<'_>) -> Option<&'a str> {
+        let mut ours = ShortName(self.module_path()?).segments();
+        let mut theirs = ShortName(other.module_path()?).segments();
+
+        let mut end = None;
+        while let Some((offset, segment)) = ours.next_spanned() {
+            if theirs.next() != Some(segment) {
+                break;
+            }
+            end = Some(offset + segment.len());
+        }
+        end.map(|end| &self.0[..end])
+    }
+
+    /// Gets the path containing the item named by the outermost path of the
+    /// original name, being everything before its final `::` segment, such as
+    /// `foo::Bar<T>` for `foo::Bar<T>::new`.
+    ///
+    /// Unlike [`module_path`](Self::module_path), this makes no guess as to which
+    /// segments are modules, so calling it repeatedly walks up one segment at a
+    /// time. Returns [`None`] if the path has only a single segment, or if the name
+    /// does not start with a path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use disqualified::ShortName;
+    /// let name = ShortName("bevy_render::camera::Camera<bevy_math::Rect>");
+    /// let parent = name.parent().unwrap();
+    /// assert_eq!(parent.original(), "bevy_render::camera");
+    /// assert_eq!(parent.parent().unwrap().original(), "bevy_render");
+    /// assert_eq!(ShortName("bevy_render").parent(), None);
+    /// ```
+    pub fn parent(&self) -> Option<ShortName<'a>> {
+        let mut segments = self.segments();
+        let mut last = None;
+        while let Some((offset, _)) = segments.next_spanned() {
+            last = Some(offset);
+        }
+        self.0[..last?].strip_suffix("::").map(ShortName)
+    }
 }
 
 impl<'a> From<&'a str> for ShortName<'a> {
@@ -46,92 +1226,455 @@ impl<'a> From<&'a str> for ShortName<'a> {
     }
 }
 
-impl<'a> core::fmt::Debug for ShortName<'a> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let &ShortName(full_name) = self;
-        // Generics result in nested paths within <..> blocks.
-        // Consider "bevy_render::camera::camera::extract_cameras<bevy_render::camera::bundle::Camera3d>".
-        // To tackle this, we parse the string from left to right, collapsing as we go.
-        let mut index: usize = 0;
-        let end_of_string = full_name.len();
-
-        while index < end_of_string {
-            let rest_of_string = full_name.get(index..end_of_string).unwrap_or_default();
-
-            // Collapse everything up to the next special character,
-            // then skip over it
-            if let Some(special_character_index) = rest_of_string.find(|c: char| {
-                (c == ' ')
-                    || (c == '<')
-                    || (c == '>')
-                    || (c == '(')
-                    || (c == ')')
-                    || (c == '[')
-                    || (c == ']')
-                    || (c == ',')
-                    || (c == ';')
-            }) {
-                let segment_to_collapse = rest_of_string
-                    .get(0..special_character_index)
-                    .unwrap_or_default();
-
-                f.write_str(collapse_type_name(segment_to_collapse))?;
-
-                // Insert the special character
-                let special_character =
-                    &rest_of_string[special_character_index..=special_character_index];
-
-                f.write_str(special_character)?;
-
-                match special_character {
-                    ">" | ")" | "]"
-                        if rest_of_string[special_character_index + 1..].starts_with("::") =>
-                    {
-                        f.write_str("::")?;
-                        // Move the index past the "::"
-                        index += special_character_index + 3;
+/// Borrows the name, whether it is borrowed or owned by the [`Cow`](alloc::borrow::Cow).
+#[cfg(feature = "alloc")]
+impl<'a> From<&'a alloc::borrow::Cow<'_, str>> for ShortName<'a> {
+    fn from(value: &'a alloc::borrow::Cow<'_, str>) -> Self {
+        Self(value)
+    }
+}
+
+/// The longest shortened name which [`String::from`] builds on the stack first.
+#[cfg(feature = "alloc")]
+const STACK_BUFFER_LEN: usize = 128;
+
+/// Gets the shortened name as a [`String`](alloc::string::String).
+///
+/// This is faster than [`to_string`](alloc::string::ToString::to_string), as the
+/// name is built up on the stack and then copied into a string of exactly the
+/// right size, without growing it along the way. Longer names are measured first
+/// instead.
+#[cfg(feature = "alloc")]
+impl<'a> From<ShortName<'a>> for alloc::string::String {
+    fn from(name: ShortName<'a>) -> Self {
+        let mut buffer = [0; STACK_BUFFER_LEN];
+        match name.shorten_into_slice(&mut buffer) {
+            Ok(short) => short.into(),
+            Err(CapacityError { required, .. }) => {
+                let mut string = Self::with_capacity(required);
+                // Writing to a `String` cannot fail.
+                let _ = name.write_to(&mut string);
+                string
+            }
+        }
+    }
+}
+
+impl<'a> ShortName<'a> {
+    /// Writes the shortened name, ignoring any formatting flags.
+    pub(crate) fn write_short(&self, f: &mut dyn core::fmt::Write) -> core::fmt::Result {
+        self.chunks().try_for_each(|chunk| f.write_str(chunk))
+    }
+
+    /// Gets an iterator over the slices of the original name which make up the
+    /// shortened name, following the default rules.
+    pub(crate) fn chunks(&self) -> Chunks<'a> {
+        Chunks {
+            name: self.0,
+            index: 0,
+            #[cfg(not(disqualified_minimal))]
+            special: None,
+        }
+    }
+
+    /// Gets the shortened name as it is displayed, for comparing and searching it.
+    ///
+    /// This only allocates when a default configuration is set.
+    pub(crate) fn output(&self) -> Output<'a> {
+        #[cfg(feature = "std")]
+        if let Some(config) = crate::config::default_config() {
+            let mut text = alloc::string::String::new();
+            // Writing to a `String` cannot fail.
+            let _ = self.with_config(&config).write_to(&mut text);
+            return Output::Rendered(text);
+        }
+        Output::Short(self.chunks())
+    }
+}
+
+/// The shortened name as it is displayed, whether or not a default configuration
+/// is set.
+#[derive(Clone, Debug)]
+pub(crate) enum Output<'a> {
+    /// Shortened by the default rules, as slices of the original name.
+    Short(Chunks<'a>),
+    /// Rendered following the default configuration.
+    #[cfg(feature = "std")]
+    Rendered(alloc::string::String),
+}
+
+impl<'a> Output<'a> {
+    /// Gets an iterator over slices which together make up the shortened name.
+    pub(crate) fn chunks(&self) -> OutputChunks<'_> {
+        match self {
+            Output::Short(chunks) => OutputChunks::Short(chunks.clone()),
+            #[cfg(feature = "std")]
+            Output::Rendered(text) => OutputChunks::Whole(Some(text)),
+        }
+    }
+}
+
+/// An iterator over the slices of an [`Output`].
+#[derive(Clone, Debug)]
+pub(crate) enum OutputChunks<'s> {
+    Short(Chunks<'s>),
+    #[cfg(feature = "std")]
+    Whole(Option<&'s str>),
+}
+
+impl<'s> Iterator for OutputChunks<'s> {
+    type Item = &'s str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            OutputChunks::Short(chunks) => chunks.next(),
+            #[cfg(feature = "std")]
+            OutputChunks::Whole(text) => text.take(),
+        }
+    }
+}
+
+impl<'s> core::iter::FusedIterator for OutputChunks<'s> {}
+
+/// An iterator over the slices of a name which make up its shortened form.
+///
+/// Generics result in nested paths within <..> blocks.
+/// Consider "bevy_render::camera::camera::extract_cameras<bevy_render::camera::bundle::Camera3d>".
+/// To tackle this, we parse the string from left to right in a single pass,
+/// remembering where the last two segments of the current path start, and
+/// collapsing the path when a special character ends it.
+/// Slices of the name are returned as they are found, so nothing is buffered.
+///
+/// Built with `--cfg disqualified_minimal`, every segment followed by `::` is
+/// dropped instead, without regard for what comes before it.
+#[derive(Clone, Debug)]
+pub(crate) struct Chunks<'a> {
+    name: &'a str,
+    /// Where the next path starts, or past the end once the name is done.
+    index: usize,
+    /// The special character which ended the last path, if it is still to come.
+    #[cfg(not(disqualified_minimal))]
+    special: Option<&'a str>,
+}
+
+#[cfg(disqualified_minimal)]
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.name.as_bytes();
+        let mut start = self.index;
+        let mut segment = start;
+        let mut index = start;
+        while index < bytes.len() {
+            match bytes[index] {
+                // The segment before a `::` is dropped, unless it follows a closing
+                // bracket, as after the `>` in `Assets<Image>::asset_event_system`.
+                b':' if bytes.get(index + 1) == Some(&b':')
+                    && !(index > 0 && matches!(bytes[index - 1], b'>' | b')' | b']')) =>
+                {
+                    let end = segment;
+                    index += 2;
+                    self.index = index;
+                    if start < end {
+                        return Some(&self.name[start..end]);
                     }
-                    // Move the index just past the special character
-                    _ => index += special_character_index + 1,
+                    start = index;
+                    segment = index;
+                    continue;
+                }
+                b' ' | b'<' | b'>' | b'(' | b')' | b'[' | b']' | b',' | b';' => {
+                    segment = index + 1;
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+        self.index = bytes.len();
+        (start < bytes.len()).then(|| &self.name[start..])
+    }
+}
+
+#[cfg(not(disqualified_minimal))]
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(special) = self.special.take() {
+                return Some(special);
+            }
+            let full_name = self.name;
+            let bytes = full_name.as_bytes();
+            if self.index > bytes.len() {
+                return None;
+            }
+
+            let mut index = self.index;
+            let mut last_segment = index;
+            let mut second_last_segment = None;
+            while index < bytes.len() {
+                let byte = bytes[index];
+                // Only the last two colons of a longer run separate segments
+                if byte == b':'
+                    && bytes.get(index + 1) == Some(&b':')
+                    && bytes.get(index + 2) != Some(&b':')
+                {
+                    second_last_segment = Some(last_segment);
+                    index += 2;
+                    last_segment = index;
+                } else if SPECIAL_CHARACTERS[byte as usize] {
+                    break;
+                } else {
+                    index += 1;
+                }
+            }
+
+            // Keeps an uppercase, and so assumed to be a type, second last segment,
+            // so that enum variants are retained.
+            let start = match second_last_segment {
+                Some(second_last) if full_name[second_last..].starts_with(char::is_uppercase) => {
+                    second_last
+                }
+                _ => last_segment,
+            };
+            let collapsed = &full_name[start..index];
+
+            // The special character comes next, with a "::" after a closing bracket
+            let special_len = match bytes.get(index) {
+                None => 1,
+                Some(b'>' | b')' | b']') if full_name[index + 1..].starts_with("::") => 3,
+                Some(_) => 1,
+            };
+            self.special = full_name.get(index..index + special_len);
+            self.index = index + special_len;
+
+            if !collapsed.is_empty() {
+                return Some(collapsed);
+            }
+        }
+    }
+}
+
+impl<'a> core::iter::FusedIterator for Chunks<'a> {}
+
+/// Names are equal when their shortened names are, so `a::Player` equals
+/// `b::Player`. Both are shortened as they are compared, without allocating
+/// unless a default configuration is set.
+impl<'a, 'b> PartialEq<ShortName<'b>> for ShortName<'a> {
+    fn eq(&self, other: &ShortName<'b>) -> bool {
+        let (ours, theirs) = (self.output(), other.output());
+        ours.chunks()
+            .flat_map(str::bytes)
+            .eq(theirs.chunks().flat_map(str::bytes))
+    }
+}
+
+impl<'a> Eq for ShortName<'a> {}
+
+/// Compares the shortened name, as it is displayed, with a string.
+impl<'a> PartialEq<str> for ShortName<'a> {
+    fn eq(&self, other: &str) -> bool {
+        let mut rest = other;
+        self.output()
+            .chunks()
+            .all(|chunk| match rest.strip_prefix(chunk) {
+                Some(tail) => {
+                    rest = tail;
+                    true
+                }
+                None => false,
+            })
+            && rest.is_empty()
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b str> for ShortName<'a> {
+    fn eq(&self, other: &&'b str) -> bool {
+        *self == **other
+    }
+}
+
+impl<'a> PartialEq<ShortName<'a>> for str {
+    fn eq(&self, other: &ShortName<'a>) -> bool {
+        *other == *self
+    }
+}
+
+impl<'a> PartialEq<ShortName<'a>> for &str {
+    fn eq(&self, other: &ShortName<'a>) -> bool {
+        *other == **self
+    }
+}
+
+/// Orders names by their shortened names, byte by byte as for [`str`], so that
+/// sorting a list of names sorts it as it is displayed.
+impl<'a, 'b> PartialOrd<ShortName<'b>> for ShortName<'a> {
+    fn partial_cmp(&self, other: &ShortName<'b>) -> Option<core::cmp::Ordering> {
+        // Both names are narrowed to the shorter of their lifetimes to compare them.
+        Some(Ord::cmp(&ShortName(self.0), &ShortName(other.0)))
+    }
+}
+
+impl<'a> Ord for ShortName<'a> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        let (ours, theirs) = (self.output(), other.output());
+        ours.chunks()
+            .flat_map(str::bytes)
+            .cmp(theirs.chunks().flat_map(str::bytes))
+    }
+}
+
+/// Hashes the shortened name as it is displayed, consistently with [`PartialEq`].
+///
+/// As the hash follows the default configuration, it should not be changed with
+/// [`set_default_config`](crate::set_default_config) while names are kept in a
+/// map, as with any change to the keys of a map.
+impl<'a> core::hash::Hash for ShortName<'a> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        // Hashers may treat the same bytes split up differently, so they are always
+        // passed on in blocks of the same size, however the name is split up.
+        const BLOCK_LEN: usize = 64;
+        let mut block = [0; BLOCK_LEN];
+        let mut len = 0;
+        for chunk in self.output().chunks() {
+            let mut bytes = chunk.as_bytes();
+            while !bytes.is_empty() {
+                let count = (BLOCK_LEN - len).min(bytes.len());
+                block[len..len + count].copy_from_slice(&bytes[..count]);
+                len += count;
+                bytes = &bytes[count..];
+                if len == BLOCK_LEN {
+                    state.write(&block);
+                    len = 0;
                 }
-            } else {
-                // If there are no special characters left, we're done!
-                f.write_str(collapse_type_name(rest_of_string))?;
-                index = end_of_string;
             }
         }
+        state.write(&block[..len]);
+        // As for `str`, this keeps names from running into what is hashed after them.
+        state.write_u8(0xff);
+    }
+}
 
-        Ok(())
+impl<'a> core::fmt::Debug for ShortName<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::display::pad(f, |mut w| self.write_to(&mut w))
     }
 }
 
 impl<'a> core::fmt::Display for ShortName<'a> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            return f.pad(self.0);
+        }
         <Self as core::fmt::Debug>::fmt(self, f)
     }
 }
 
-#[inline(always)]
-fn collapse_type_name(string: &str) -> &str {
-    // Enums types are retained.
-    // As heuristic, we assume the enum type to be uppercase.
-    let mut segments = string.rsplit("::");
-    let (last, second_last): (&str, Option<&str>) = (segments.next().unwrap(), segments.next());
-    let Some(second_last) = second_last else {
-        return last;
-    };
+/// Hashes the addresses and lengths which key [`ShortName::cached`] with a single
+/// multiplication each, as they need no protection from collisions.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Default)]
+struct AddressHasher(u64);
+
+#[cfg(feature = "std")]
+impl core::hash::BuildHasher for AddressHasher {
+    type Hasher = Self;
+
+    fn build_hasher(&self) -> Self {
+        Self(0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::hash::Hasher for AddressHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        bytes.iter().for_each(|&byte| self.write_u64(byte.into()));
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
 
-    if second_last.starts_with(char::is_uppercase) {
-        let index = string.len() - last.len() - second_last.len() - 2;
-        &string[index..]
-    } else {
-        last
+    fn write_u64(&mut self, value: u64) {
+        self.0 = (self.0.rotate_left(5) ^ value).wrapping_mul(0x517c_c1b7_2722_0a95);
     }
 }
 
+/// Whether each byte is one of the characters which separate the paths in a name.
+///
+/// The characters are all ASCII, so the bytes of a name can be looked up in this
+/// table rather than decoding and comparing each character in turn.
+///
+/// This is kept instead of searching with `memchr`, which can look for at most
+/// three bytes at once, so finding all ten takes four searches which each stop
+/// every few bytes in type names. Counting the separators in an 8 MB dump of
+/// Bevy system and component names took 2.2 ms with this table and 28.5 ms with
+/// `memchr`, next to 12.4 ms for shortening the whole dump. `memchr` only came
+/// out ahead, by 20%, on made-up names with a thousand bytes between separators.
+#[cfg(not(disqualified_minimal))]
+const SPECIAL_CHARACTERS: [bool; 256] = {
+    let mut table = [false; 256];
+    let special = b" <>()[],;";
+    let mut i = 0;
+    while i < special.len() {
+        table[special[i] as usize] = true;
+        i += 1;
+    }
+    table
+};
+
+/// Shortens every name in `names`, returning the shortened names in the same order.
+///
+/// # Examples
+///
+/// ```rust
+/// let names = disqualified::shorten_all(["my_game::Player", "alloc::vec::Vec<my_game::Item>"]);
+/// assert_eq!(names, ["Player", "Vec<Item>"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn shorten_all<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+) -> alloc::vec::Vec<alloc::string::String> {
+    names
+        .into_iter()
+        .map(|name| ShortName(name).into())
+        .collect()
+}
+
+/// Shortens every name in `names` in parallel, returning the shortened names in the
+/// same order.
+///
+/// This is worth it for large batches, such as symbol tables with hundreds of
+/// thousands of entries.
+///
+/// # Examples
+///
+/// ```rust
+/// let symbols = vec!["my_game::Player", "alloc::vec::Vec<my_game::Item>"];
+/// let names = disqualified::par_shorten_all(symbols);
+/// assert_eq!(names, ["Player", "Vec<Item>"]);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_shorten_all<'a>(
+    names: impl rayon::iter::IntoParallelIterator<Item = &'a str>,
+) -> alloc::vec::Vec<alloc::string::String> {
+    use rayon::iter::ParallelIterator;
+
+    names
+        .into_par_iter()
+        .map(|name| ShortName(name).into())
+        .collect()
+}
+
 #[cfg(all(test, feature = "alloc"))]
 mod name_formatting_tests {
     use super::ShortName;
+    use alloc::string::ToString;
 
     #[test]
     fn trivial() {
@@ -169,6 +1712,7 @@ mod name_formatting_tests {
         assert_eq!(ShortName("a<B, C>").to_string(), "a<B, C>");
     }
 
+    #[cfg(not(disqualified_minimal))]
     #[test]
     fn enums() {
         assert_eq!(ShortName("Option::None").to_string(), "Option::None");
@@ -179,6 +1723,239 @@ mod name_formatting_tests {
         );
     }
 
+    #[test]
+    fn into_string() {
+        use alloc::string::String;
+
+        let short = String::from(ShortName("alloc::vec::Vec<my_game::Player>"));
+        assert_eq!(short, "Vec<Player>");
+        assert_eq!(short.capacity(), short.len());
+
+        let long = "my_game::Events<my_game::VeryLongEventNameNumberOne, my_game::VeryLongEventNameNumberTwo, my_game::VeryLongEventNameNumberThree, my_game::VeryLongEventNameNumberFour, my_game::VeryLongEventNameNumberFive>";
+        let short = String::from(ShortName(long));
+        assert!(short.len() > super::STACK_BUFFER_LEN);
+        assert_eq!(short, ShortName(long).to_string());
+        assert_eq!(short.capacity(), short.len());
+        assert_eq!(String::from(ShortName("")), "");
+
+        assert_eq!(&*ShortName(long).boxed(), short);
+        assert_eq!(&*ShortName("a::B<c::D>").boxed(), "B<D>");
+        assert_eq!(&*ShortName("").boxed(), "");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn static_names() {
+        let name = alloc::format!("{}::Unique<{}::Name>", "my_game", "my_game");
+        let short = ShortName(&name).into_static();
+        assert_eq!(short, "Unique<Name>");
+        assert!(core::ptr::eq(
+            short,
+            ShortName("a::Unique<b::Name>").into_static()
+        ));
+
+        struct Unique<T>(T);
+        struct Name;
+        assert!(core::ptr::eq(short, ShortName::cached::<Unique<Name>>()));
+        assert_eq!(ShortName("").into_static(), "");
+    }
+
+    #[test]
+    fn bulk_shortening() {
+        let names = ["a::B", "c::D<e::F>", "", "g::H"];
+        assert_eq!(super::shorten_all(names), ["B", "D<F>", "", "H"]);
+        assert!(super::shorten_all([]).is_empty());
+        #[cfg(feature = "rayon")]
+        assert_eq!(super::par_shorten_all(names), super::shorten_all(names));
+    }
+
+    #[cfg(not(disqualified_minimal))]
+    #[test]
+    fn already_short() {
+        let already_short = |name| ShortName(name).is_already_short();
+
+        assert!(already_short(""));
+        assert!(already_short("u32"));
+        assert!(already_short("(u8, [i32; 3])"));
+        assert!(already_short("Option::Some(2)"));
+        assert!(already_short("Assets<Image>::asset_event_system"));
+        assert!(!already_short("a::B"));
+        assert!(!already_short("Vec<a::B>"));
+        assert!(!already_short("Option::a::B"));
+        assert!(!already_short("a::B::C"));
+    }
+
+    #[test]
+    fn pathological_names() {
+        let name = "a::".repeat(10_000) + "B";
+        assert_eq!(ShortName(&name).to_string(), "B");
+
+        let name = "A::".repeat(10_000) + "b";
+        let expected = if cfg!(disqualified_minimal) {
+            "b"
+        } else {
+            "A::b"
+        };
+        assert_eq!(ShortName(&name).to_string(), expected);
+
+        let name = "a::b<".repeat(10_000);
+        assert_eq!(ShortName(&name).to_string(), "b<".repeat(10_000));
+
+        let name = ":".repeat(10_001);
+        let expected = if cfg!(disqualified_minimal) { ":" } else { "" };
+        assert_eq!(ShortName(&name).to_string(), expected);
+    }
+
+    #[test]
+    fn equality_and_hashing() {
+        use alloc::vec::Vec;
+        use core::hash::{Hash, Hasher};
+
+        /// Records each write, to check that equal names hash the same way.
+        #[derive(Default)]
+        struct Recorder(Vec<Vec<u8>>);
+
+        impl Hasher for Recorder {
+            fn finish(&self) -> u64 {
+                0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                self.0.push(bytes.into());
+            }
+        }
+
+        fn hash(name: &str) -> Vec<Vec<u8>> {
+            let mut recorder = Recorder::default();
+            ShortName(name).hash(&mut recorder);
+            recorder.0
+        }
+
+        assert_eq!(ShortName("a::B<c::D>"), ShortName("e::f::B<D>"));
+        assert_eq!(hash("a::B<c::D>"), hash("e::f::B<D>"));
+        assert_ne!(ShortName("a::B<c::D>"), ShortName("a::B<c::E>"));
+        assert_ne!(ShortName("a::B"), ShortName("a::BC"));
+        assert_eq!(ShortName(""), ShortName("a::"));
+
+        let long = "x::".repeat(50) + &"y".repeat(100) + "<z::W>";
+        assert_eq!(hash(&long), hash(&("y".repeat(100) + "<W>")));
+        assert_eq!(hash(&long)[0].len(), 64);
+    }
+
+    #[test]
+    fn equality_with_strings() {
+        let name = ShortName("bevy_transform::components::Transform");
+        assert!(name == "Transform");
+        assert!("Transform" == name);
+        assert!(*"Transform" == name);
+        assert!(name != "Transfor");
+        assert!(name != "Transforms");
+        assert!(name != "bevy_transform::components::Transform");
+        assert_eq!(ShortName("a::B<c::D>"), "B<D>");
+        assert_eq!(ShortName(""), "");
+        assert_ne!(ShortName("a::B"), "");
+    }
+
+    #[test]
+    fn prefixes_and_suffixes() {
+        let name = ShortName("a::B<c::D, e::F>");
+        for prefix in ["", "B", "B<", "B<D", "B<D, F>"] {
+            assert!(name.starts_with(prefix), "{prefix}");
+        }
+        for prefix in ["a", "B<c", "B<D, F>>", "C"] {
+            assert!(!name.starts_with(prefix), "{prefix}");
+        }
+        for suffix in ["", ">", "F>", ", F>", "D, F>", "B<D, F>"] {
+            assert!(name.ends_with(suffix), "{suffix}");
+        }
+        for suffix in ["e::F>", "D>", "<B<D, F>", "F"] {
+            assert!(!name.ends_with(suffix), "{suffix}");
+        }
+        assert!(ShortName("").starts_with(""));
+        assert!(ShortName("").ends_with(""));
+        assert!(!ShortName("").ends_with("a"));
+        assert!(ShortName("a::Größe").ends_with("öße"));
+    }
+
+    #[test]
+    fn substrings() {
+        let name = ShortName("a::Bb<c::Dd, e::Ff>");
+        let short = name.to_string();
+        for start in 0..short.len() {
+            for end in start..=short.len() {
+                assert!(name.contains(&short[start..end]), "{}", &short[start..end]);
+            }
+        }
+        for needle in ["a::Bb", "c::", "Dd>", "Ff>>", "BbB", "x"] {
+            assert!(!name.contains(needle), "{needle}");
+        }
+        assert!(ShortName("").contains(""));
+        assert!(!ShortName("").contains("a"));
+        assert!(ShortName("a::aab").contains("ab"));
+    }
+
+    #[test]
+    fn ordering() {
+        use alloc::vec::Vec;
+        use core::cmp::Ordering;
+
+        let mut names = [
+            ShortName("bevy_transform::Transform"),
+            ShortName("bevy_core::Name"),
+            ShortName("a::Option<z::A>"),
+            ShortName("b::Option<a::Z>"),
+            ShortName("my_game::Player"),
+        ];
+        names.sort();
+        let sorted: Vec<_> = names.iter().map(|name| name.to_string()).collect();
+        assert_eq!(
+            sorted,
+            ["Name", "Option<A>", "Option<Z>", "Player", "Transform"]
+        );
+
+        assert_eq!(ShortName("a::B").cmp(&ShortName("c::B")), Ordering::Equal);
+        assert!(ShortName("a::B") < ShortName("a::BC"));
+        assert!(ShortName("z::A") < ShortName("a::B"));
+    }
+
+    #[cfg(disqualified_minimal)]
+    #[test]
+    fn minimal() {
+        let short = |name| ShortName(name).to_string();
+        assert_eq!(
+            short("alloc::vec::Vec<core::option::Option<u32>>"),
+            "Vec<Option<u32>>"
+        );
+        assert_eq!(short("bevy_render::RenderSet::Prepare"), "Prepare");
+        assert_eq!(
+            short("bevy_asset::Assets<bevy_image::Image>::asset_event_system"),
+            "Assets<Image>::asset_event_system"
+        );
+        assert_eq!(short("<a::B as c::D>::e"), "<B as D>::e");
+        assert_eq!(short("(a::B, [c::D; 3])"), "(B, [D; 3])");
+        assert_eq!(short("a::"), "");
+        assert_eq!(short("a::::b"), "b");
+        assert_eq!(short(""), "");
+    }
+
+    #[cfg(not(disqualified_minimal))]
+    #[test]
+    fn many_segments() {
+        assert_eq!(
+            ShortName("a::b::c::d::e::f::G<h::i::J::K, l::m::N>::o").to_string(),
+            "G<J::K, N>::o"
+        );
+        assert_eq!(ShortName("a:::b").to_string(), "b");
+    }
+
+    #[test]
+    fn non_ascii_identifiers() {
+        assert_eq!(
+            ShortName("spiel::ökonomie::Währung<spiel::Münze>").to_string(),
+            "Währung<Münze>"
+        );
+    }
+
     #[test]
     fn generics() {
         assert_eq!(
@@ -210,4 +1987,79 @@ mod name_formatting_tests {
             "[i32; 16]::default"
         );
     }
+
+    #[test]
+    fn shortened_len() {
+        for name in [
+            "bevy_render::camera::camera::extract_cameras<bevy_render::camera::bundle::Camera3d>",
+            "(String, String)::default",
+            "my_game::Größe<my_game::Maß>",
+            "",
+        ] {
+            let short = ShortName(name).to_string();
+            assert_eq!(ShortName(name).shortened_len(), short.len(), "{name}");
+            assert_eq!(
+                ShortName(name).shortened_char_count(),
+                short.chars().count(),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn to_cow() {
+        use alloc::borrow::Cow;
+
+        for (name, borrowed) in [
+            ("bevy_render::camera::Camera3d", true),
+            ("bevy_render::RenderSet::Prepare", true),
+            ("u8", true),
+            ("", true),
+            ("alloc::vec::Vec<u8>", true),
+            ("alloc::vec::Vec<my_game::Player>", false),
+            ("(my_game::A, my_game::B)", false),
+            ("[u8; 3]", true),
+        ] {
+            let cow = ShortName(name).to_cow();
+            assert_eq!(cow, ShortName(name).to_string(), "{name}");
+            assert_eq!(matches!(cow, Cow::Borrowed(_)), borrowed, "{name}");
+        }
+    }
+
+    #[test]
+    fn shorten_into() {
+        let mut buffer = alloc::string::String::from("previous contents");
+
+        ShortName("bevy_render::camera::Camera3d").shorten_into(&mut buffer);
+        assert_eq!(buffer, "Camera3d");
+        ShortName("alloc::vec::Vec<my_game::Player>").shorten_into(&mut buffer);
+        assert_eq!(buffer, "Vec<Player>");
+        ShortName("").shorten_into(&mut buffer);
+        assert_eq!(buffer, "");
+    }
+
+    #[test]
+    fn shorten_into_slice() {
+        let name = ShortName("bevy_render::camera::extract_cameras<bevy_render::camera::Camera3d>");
+        let mut buffer = [0; 32];
+
+        assert_eq!(
+            name.shorten_into_slice(&mut buffer),
+            Ok("extract_cameras<Camera3d>")
+        );
+        assert_eq!(
+            name.shorten_into_slice(&mut buffer[..25]),
+            Ok("extract_cameras<Camera3d>")
+        );
+        let error = name.shorten_into_slice(&mut buffer[..24]).unwrap_err();
+        assert_eq!((error.required, error.available), (25, 24));
+        assert_eq!(ShortName("").shorten_into_slice(&mut []), Ok(""));
+        assert_eq!(
+            ShortName("my_game::Größe").shorten_into_slice(&mut buffer[..6]),
+            Err(crate::CapacityError {
+                required: 7,
+                available: 6
+            })
+        );
+    }
 }
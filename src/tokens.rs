@@ -0,0 +1,283 @@
+/// The kind of a [`Token`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// An identifier or keyword, such as `bevy_render`, `Vec`, `dyn` or `mut`.
+    Ident,
+    /// A lifetime, such as `'a` or `'static`.
+    Lifetime,
+    /// A literal, such as `3`, `-1`, `'x'` or `"C"`.
+    Literal,
+    /// `::`
+    PathSep,
+    /// `<`
+    Lt,
+    /// `>`
+    Gt,
+    /// `,`
+    Comma,
+    /// `;`
+    Semicolon,
+    /// `:`
+    Colon,
+    /// `(`
+    OpenParen,
+    /// `)`
+    CloseParen,
+    /// `[`
+    OpenBracket,
+    /// `]`
+    CloseBracket,
+    /// `{`
+    OpenBrace,
+    /// `}`
+    CloseBrace,
+    /// `&`
+    And,
+    /// `*`
+    Star,
+    /// `+`
+    Plus,
+    /// `=`
+    Eq,
+    /// `!`
+    Bang,
+    /// `#`
+    Pound,
+    /// `->`
+    Arrow,
+    /// A run of whitespace.
+    Space,
+    /// Any other single character.
+    Other,
+}
+
+/// A single token of a type name, borrowed from the original string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Token<'a> {
+    /// What kind of token this is.
+    pub kind: TokenKind,
+    /// The text of the token.
+    pub text: &'a str,
+    /// The byte offset of the token within the original string.
+    pub offset: usize,
+}
+
+/// Lexes a type name into [`Token`]s without allocating.
+///
+/// Every byte of the name belongs to exactly one token, so concatenating the
+/// text of all tokens reproduces the original name.
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::{TokenKind, TypeNameTokens};
+/// let kinds: Vec<_> = TypeNameTokens::new("a::B<C>")
+///     .map(|token| token.kind)
+///     .collect();
+/// assert_eq!(
+///     kinds,
+///     [
+///         TokenKind::Ident,
+///         TokenKind::PathSep,
+///         TokenKind::Ident,
+///         TokenKind::Lt,
+///         TokenKind::Ident,
+///         TokenKind::Gt,
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct TypeNameTokens<'a> {
+    name: &'a str,
+    offset: usize,
+}
+
+impl<'a> TypeNameTokens<'a> {
+    /// Creates a token iterator over `name`.
+    pub const fn new(name: &'a str) -> Self {
+        Self { name, offset: 0 }
+    }
+}
+
+impl<'a> Iterator for TypeNameTokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.name[self.offset..];
+        let mut chars = rest.chars();
+        let first = chars.next()?;
+
+        let (kind, len) = match first {
+            ':' if rest.starts_with("::") => (TokenKind::PathSep, 2),
+            '-' if rest.starts_with("->") => (TokenKind::Arrow, 2),
+            '-' if rest[1..].starts_with(|c: char| c.is_ascii_digit()) => {
+                (TokenKind::Literal, 1 + ident_len(&rest[1..]))
+            }
+            '<' => (TokenKind::Lt, 1),
+            '>' => (TokenKind::Gt, 1),
+            ',' => (TokenKind::Comma, 1),
+            ';' => (TokenKind::Semicolon, 1),
+            ':' => (TokenKind::Colon, 1),
+            '(' => (TokenKind::OpenParen, 1),
+            ')' => (TokenKind::CloseParen, 1),
+            '[' => (TokenKind::OpenBracket, 1),
+            ']' => (TokenKind::CloseBracket, 1),
+            '{' => (TokenKind::OpenBrace, 1),
+            '}' => (TokenKind::CloseBrace, 1),
+            '&' => (TokenKind::And, 1),
+            '*' => (TokenKind::Star, 1),
+            '+' => (TokenKind::Plus, 1),
+            '=' => (TokenKind::Eq, 1),
+            '!' => (TokenKind::Bang, 1),
+            '#' => (TokenKind::Pound, 1),
+            '"' => (TokenKind::Literal, string_len(rest)),
+            '\'' => quote_len(rest),
+            c if c.is_ascii_digit() => (TokenKind::Literal, ident_len(rest)),
+            c if is_ident_char(c) => (TokenKind::Ident, ident_len(rest)),
+            c if c.is_whitespace() => {
+                let len = rest
+                    .find(|c: char| !c.is_whitespace())
+                    .unwrap_or(rest.len());
+                (TokenKind::Space, len)
+            }
+            c => (TokenKind::Other, c.len_utf8()),
+        };
+
+        let token = Token {
+            kind,
+            text: &rest[..len],
+            offset: self.offset,
+        };
+        self.offset += len;
+        Some(token)
+    }
+}
+
+impl<'a> core::iter::FusedIterator for TypeNameTokens<'a> {}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Length of the run of identifier characters at the start of `s`.
+fn ident_len(s: &str) -> usize {
+    s.find(|c: char| !is_ident_char(c)).unwrap_or(s.len())
+}
+
+/// Length of the string literal at the start of `s`, including both quotes.
+fn string_len(s: &str) -> usize {
+    let mut escaped = false;
+    for (index, c) in s.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return index + 1,
+            _ => {}
+        }
+    }
+    s.len()
+}
+
+/// Distinguishes a char literal (`'x'`) from a lifetime (`'a`) at the start of `s`.
+fn quote_len(s: &str) -> (TokenKind, usize) {
+    let rest = &s[1..];
+    let mut chars = rest.char_indices();
+    match chars.next() {
+        Some((_, '\\')) => {
+            // An escaped char literal, such as `'\n'` or `'\u{1F600}'`.
+            let len = rest
+                .get(2..)
+                .and_then(|rest| rest.find('\''))
+                .map_or(s.len(), |end| end + 4);
+            (TokenKind::Literal, len)
+        }
+        Some((_, c)) => match chars.next() {
+            Some((index, '\'')) => (TokenKind::Literal, index + 2),
+            _ if is_ident_char(c) => (TokenKind::Lifetime, 1 + ident_len(rest)),
+            _ => (TokenKind::Other, 1),
+        },
+        None => (TokenKind::Other, 1),
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod token_tests {
+    use super::{TokenKind, TypeNameTokens};
+    use alloc::vec::Vec;
+
+    #[track_caller]
+    fn lex(name: &str) -> Vec<(TokenKind, &str)> {
+        TypeNameTokens::new(name)
+            .map(|token| (token.kind, token.text))
+            .collect()
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(
+            lex("bevy_render::Camera3d"),
+            [
+                (TokenKind::Ident, "bevy_render"),
+                (TokenKind::PathSep, "::"),
+                (TokenKind::Ident, "Camera3d"),
+            ]
+        );
+    }
+
+    #[test]
+    fn punctuation() {
+        assert_eq!(
+            lex("(&'a [u8; 3], fn() -> !)"),
+            [
+                (TokenKind::OpenParen, "("),
+                (TokenKind::And, "&"),
+                (TokenKind::Lifetime, "'a"),
+                (TokenKind::Space, " "),
+                (TokenKind::OpenBracket, "["),
+                (TokenKind::Ident, "u8"),
+                (TokenKind::Semicolon, ";"),
+                (TokenKind::Space, " "),
+                (TokenKind::Literal, "3"),
+                (TokenKind::CloseBracket, "]"),
+                (TokenKind::Comma, ","),
+                (TokenKind::Space, " "),
+                (TokenKind::Ident, "fn"),
+                (TokenKind::OpenParen, "("),
+                (TokenKind::CloseParen, ")"),
+                (TokenKind::Space, " "),
+                (TokenKind::Arrow, "->"),
+                (TokenKind::Space, " "),
+                (TokenKind::Bang, "!"),
+                (TokenKind::CloseParen, ")"),
+            ]
+        );
+    }
+
+    #[test]
+    fn literals() {
+        assert_eq!(
+            lex(r#"-1 'x' '\'' "C" 'static"#)
+                .into_iter()
+                .filter(|(kind, _)| *kind != TokenKind::Space)
+                .collect::<Vec<_>>(),
+            [
+                (TokenKind::Literal, "-1"),
+                (TokenKind::Literal, "'x'"),
+                (TokenKind::Literal, r"'\''"),
+                (TokenKind::Literal, "\"C\""),
+                (TokenKind::Lifetime, "'static"),
+            ]
+        );
+    }
+
+    #[test]
+    fn covers_input() {
+        for name in ["a::{{closure}}#0", "'", "\"unterminated", "é::ü<:>"] {
+            let tokens = TypeNameTokens::new(name);
+            assert_eq!(
+                tokens.map(|token| token.text).collect::<Vec<_>>().concat(),
+                name
+            );
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{ParseError, ParseErrorKind, TokenKind, TypeNameTokens};
+
+/// The deepest nesting of brackets which [`validate`] will check.
+const MAX_DEPTH: usize = 64;
+
+/// Checks that `name` is well-formed enough to be shortened sensibly.
+pub(crate) fn validate(name: &str) -> Result<(), ParseError> {
+    if name.is_empty() {
+        return Err(ParseError::new(0, ParseErrorKind::UnexpectedEnd));
+    }
+
+    // The expected closing delimiter and opening offset of each open bracket.
+    let mut open = [(TokenKind::Gt, 0); MAX_DEPTH];
+    let mut depth = 0;
+    let mut previous = None;
+    let mut tokens = TypeNameTokens::new(name).peekable();
+
+    while let Some(token) = tokens.next() {
+        let error = |kind| Err(ParseError::new(token.offset, kind));
+        match token.kind {
+            TokenKind::Lt
+            | TokenKind::OpenParen
+            | TokenKind::OpenBracket
+            | TokenKind::OpenBrace => {
+                if depth == MAX_DEPTH {
+                    return error(ParseErrorKind::TooDeep);
+                }
+                open[depth] = (closing(token.kind), token.offset);
+                depth += 1;
+            }
+            TokenKind::Gt
+            | TokenKind::CloseParen
+            | TokenKind::CloseBracket
+            | TokenKind::CloseBrace => {
+                if depth == 0 || open[depth - 1].0 != token.kind {
+                    return error(ParseErrorKind::UnmatchedDelimiter);
+                }
+                depth -= 1;
+            }
+            TokenKind::PathSep => {
+                let joins_segments = matches!(
+                    previous,
+                    Some(
+                        TokenKind::Ident
+                            | TokenKind::Gt
+                            | TokenKind::CloseParen
+                            | TokenKind::CloseBracket
+                            | TokenKind::CloseBrace
+                    )
+                ) && matches!(
+                    tokens.peek().map(|token| token.kind),
+                    Some(TokenKind::Ident | TokenKind::OpenBrace | TokenKind::Lt)
+                );
+                if !joins_segments {
+                    return error(ParseErrorKind::StrayPathSeparator);
+                }
+            }
+            TokenKind::Comma | TokenKind::Semicolon if depth == 0 => {
+                return error(ParseErrorKind::UnexpectedToken);
+            }
+            // `?` appears in `?Sized`, and `.` in variadic `...` parameters.
+            TokenKind::Other if !matches!(token.text, "?" | ".") => {
+                return error(ParseErrorKind::UnexpectedToken);
+            }
+            _ => {}
+        }
+        previous = Some(token.kind);
+    }
+
+    match depth {
+        0 => Ok(()),
+        _ => Err(ParseError::new(
+            open[depth - 1].1,
+            ParseErrorKind::UnclosedDelimiter,
+        )),
+    }
+}
+
+fn closing(open: TokenKind) -> TokenKind {
+    match open {
+        TokenKind::Lt => TokenKind::Gt,
+        TokenKind::OpenParen => TokenKind::CloseParen,
+        TokenKind::OpenBracket => TokenKind::CloseBracket,
+        _ => TokenKind::CloseBrace,
+    }
+}
+
+#[cfg(test)]
+mod validate_tests {
+    use super::validate;
+    use crate::{ParseError, ParseErrorKind};
+
+    #[test]
+    fn valid() {
+        for name in [
+            "test_system",
+            "bevy_render::camera::camera::extract_cameras<bevy_render::camera::bundle::Camera3d>",
+            "bevy_asset::assets::Assets<bevy_scene::DynamicScene>::asset_event_system",
+            "(alloc::string::String, [i32; 3])::default",
+            "<T as core::default::Default>::default",
+            "&dyn core::ops::function::Fn(u32) -> u32",
+            "my_crate::system::{{closure}}",
+            "core::option::Option::Some(2)",
+        ] {
+            assert_eq!(validate(name), Ok(()), "{name}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let error = |offset, kind| Err(ParseError::new(offset, kind));
+
+        assert_eq!(validate(""), error(0, ParseErrorKind::UnexpectedEnd));
+        assert_eq!(
+            validate("a::Vec<b::Option<u32>"),
+            error(6, ParseErrorKind::UnclosedDelimiter)
+        );
+        assert_eq!(
+            validate("Vec<u32>>"),
+            error(8, ParseErrorKind::UnmatchedDelimiter)
+        );
+        assert_eq!(
+            validate("Vec<u32)"),
+            error(7, ParseErrorKind::UnmatchedDelimiter)
+        );
+        assert_eq!(
+            validate("::a::b"),
+            error(0, ParseErrorKind::StrayPathSeparator)
+        );
+        assert_eq!(
+            validate("a::::b"),
+            error(1, ParseErrorKind::StrayPathSeparator)
+        );
+        assert_eq!(
+            validate("a::b::"),
+            error(4, ParseErrorKind::StrayPathSeparator)
+        );
+        assert_eq!(validate("a, b"), error(1, ParseErrorKind::UnexpectedToken));
+        assert_eq!(
+            validate("a::$b"),
+            error(1, ParseErrorKind::StrayPathSeparator)
+        );
+        assert_eq!(validate("a$b"), error(1, ParseErrorKind::UnexpectedToken));
+        assert_eq!(
+            validate(&"<".repeat(100)),
+            error(64, ParseErrorKind::TooDeep)
+        );
+    }
+}
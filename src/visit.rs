@@ -0,0 +1,296 @@
+use crate::{TokenKind, TypeNameTokens};
+
+/// Callbacks for the structure of a type name, driven by [`walk`].
+///
+/// Every method has an empty default implementation, so implementors only need
+/// to override the callbacks they are interested in. Together, the `&str`
+/// arguments and the brackets implied by the other callbacks cover every byte
+/// of the name.
+#[allow(unused_variables)]
+pub trait TypeNameVisitor<'a> {
+    /// A path segment, such as `bevy_render`, `Vec` or `{{closure}}`.
+    fn segment(&mut self, segment: &'a str) {}
+
+    /// A `::` between path segments.
+    fn path_separator(&mut self) {}
+
+    /// The `<` opening the generic arguments of a path segment.
+    fn open_generics(&mut self) {}
+
+    /// The `>` closing the generic arguments of a path segment.
+    fn close_generics(&mut self) {}
+
+    /// The `<` opening a qualified path, as in `<T as Trait>::method`.
+    fn open_qualified(&mut self) {}
+
+    /// The `>` closing a qualified path.
+    fn close_qualified(&mut self) {}
+
+    /// The `(` opening a tuple or parenthesized type.
+    fn tuple_start(&mut self) {}
+
+    /// The `)` closing a tuple or parenthesized type.
+    fn tuple_end(&mut self) {}
+
+    /// The `(` opening parenthesized arguments, as in `Fn(u32)`, `fn(u32)` or `Some(2)`.
+    fn open_args(&mut self) {}
+
+    /// The `)` closing parenthesized arguments.
+    fn close_args(&mut self) {}
+
+    /// The `[` opening an array or slice.
+    fn array_start(&mut self) {}
+
+    /// The `]` closing an array or slice.
+    fn array_end(&mut self) {}
+
+    /// A `,` or `;` separating the elements of a bracketed group.
+    fn separator(&mut self, separator: &'a str) {}
+
+    /// A keyword, such as `dyn`, `mut` or `as`.
+    fn keyword(&mut self, keyword: &'a str) {}
+
+    /// A lifetime, such as `'static`.
+    fn lifetime(&mut self, lifetime: &'a str) {}
+
+    /// A literal, such as the `3` in `[u8; 3]`.
+    fn literal(&mut self, literal: &'a str) {}
+
+    /// Any other text, such as `&`, ` + `, `->` or whitespace.
+    fn punctuation(&mut self, punctuation: &'a str) {}
+}
+
+/// Walks the structure of a type name, calling `visitor` for each part in order.
+///
+/// Walking does not allocate, and never fails: malformed names are visited on a
+/// best effort basis. Use [`ShortName::validate`](crate::ShortName::validate) to
+/// reject them beforehand.
+///
+/// # Examples
+///
+/// ```rust
+/// # use disqualified::{walk, TypeNameVisitor};
+/// #[derive(Default)]
+/// struct CountSegments(usize);
+///
+/// impl<'a> TypeNameVisitor<'a> for CountSegments {
+///     fn segment(&mut self, _segment: &'a str) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// let mut counter = CountSegments::default();
+/// walk("alloc::vec::Vec<core::option::Option<u32>>", &mut counter);
+/// assert_eq!(counter.0, 7);
+/// ```
+pub fn walk<'a>(name: &'a str, visitor: &mut impl TypeNameVisitor<'a>) {
+    let mut tokens = TypeNameTokens::new(name);
+    // For each open `<` or `(`, whether it follows a path segment (or `fn`),
+    // which distinguishes generics and arguments from qualified paths and tuples.
+    let mut stack = 0_u128;
+    let mut depth = 0_u32;
+    let mut follows_segment = false;
+
+    while let Some(token) = tokens.next() {
+        let mut is_segment = false;
+        match token.kind {
+            TokenKind::Ident => match token.text {
+                "true" | "false" => visitor.literal(token.text),
+                "fn" => {
+                    visitor.keyword(token.text);
+                    is_segment = true;
+                }
+                text if is_keyword(text) => visitor.keyword(text),
+                text => {
+                    visitor.segment(text);
+                    is_segment = true;
+                }
+            },
+            TokenKind::OpenBrace => {
+                // Braced segments such as `{{closure}}` are visited whole.
+                let mut braces = 1_usize;
+                let mut end = name.len();
+                for token in tokens.by_ref() {
+                    match token.kind {
+                        TokenKind::OpenBrace => braces += 1,
+                        TokenKind::CloseBrace => braces -= 1,
+                        _ => {}
+                    }
+                    if braces == 0 {
+                        end = token.offset + token.text.len();
+                        break;
+                    }
+                }
+                visitor.segment(&name[token.offset..end]);
+                is_segment = true;
+            }
+            TokenKind::Lt | TokenKind::OpenParen => {
+                if depth < u128::BITS {
+                    stack = (stack << 1) | u128::from(follows_segment);
+                }
+                depth += 1;
+                match (token.kind, follows_segment) {
+                    (TokenKind::Lt, true) => visitor.open_generics(),
+                    (TokenKind::Lt, false) => visitor.open_qualified(),
+                    (_, true) => visitor.open_args(),
+                    (_, false) => visitor.tuple_start(),
+                }
+            }
+            TokenKind::Gt | TokenKind::CloseParen => {
+                let followed_segment = depth > u128::BITS || stack & 1 == 1;
+                if depth > 0 && depth <= u128::BITS {
+                    stack >>= 1;
+                }
+                depth = depth.saturating_sub(1);
+                match (token.kind, followed_segment) {
+                    (TokenKind::Gt, true) => visitor.close_generics(),
+                    (TokenKind::Gt, false) => visitor.close_qualified(),
+                    (_, true) => visitor.close_args(),
+                    (_, false) => visitor.tuple_end(),
+                }
+            }
+            TokenKind::OpenBracket => visitor.array_start(),
+            TokenKind::CloseBracket => visitor.array_end(),
+            TokenKind::PathSep => visitor.path_separator(),
+            TokenKind::Comma | TokenKind::Semicolon => visitor.separator(token.text),
+            TokenKind::Lifetime => visitor.lifetime(token.text),
+            TokenKind::Literal => visitor.literal(token.text),
+            _ => visitor.punctuation(token.text),
+        }
+        follows_segment = is_segment;
+    }
+}
+
+pub(crate) fn is_keyword(ident: &str) -> bool {
+    matches!(
+        ident,
+        "as" | "const" | "dyn" | "extern" | "for" | "impl" | "mut" | "unsafe"
+    )
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod visit_tests {
+    use super::{walk, TypeNameVisitor};
+    use alloc::{string::String, vec::Vec};
+
+    #[derive(Default)]
+    struct Events(Vec<String>);
+
+    impl<'a> TypeNameVisitor<'a> for Events {
+        fn segment(&mut self, segment: &'a str) {
+            self.0.push(segment.into());
+        }
+        fn path_separator(&mut self) {
+            self.0.push("::".into());
+        }
+        fn open_generics(&mut self) {
+            self.0.push("generics<".into());
+        }
+        fn close_generics(&mut self) {
+            self.0.push(">generics".into());
+        }
+        fn open_qualified(&mut self) {
+            self.0.push("qualified<".into());
+        }
+        fn close_qualified(&mut self) {
+            self.0.push(">qualified".into());
+        }
+        fn tuple_start(&mut self) {
+            self.0.push("tuple(".into());
+        }
+        fn tuple_end(&mut self) {
+            self.0.push(")tuple".into());
+        }
+        fn open_args(&mut self) {
+            self.0.push("args(".into());
+        }
+        fn close_args(&mut self) {
+            self.0.push(")args".into());
+        }
+        fn array_start(&mut self) {
+            self.0.push("[".into());
+        }
+        fn array_end(&mut self) {
+            self.0.push("]".into());
+        }
+        fn separator(&mut self, separator: &'a str) {
+            self.0.push(separator.into());
+        }
+        fn keyword(&mut self, keyword: &'a str) {
+            self.0.push(alloc::format!("keyword {keyword}"));
+        }
+        fn lifetime(&mut self, lifetime: &'a str) {
+            self.0.push(lifetime.into());
+        }
+        fn literal(&mut self, literal: &'a str) {
+            self.0.push(alloc::format!("literal {literal}"));
+        }
+    }
+
+    #[track_caller]
+    fn events(name: &str) -> Vec<String> {
+        let mut events = Events::default();
+        walk(name, &mut events);
+        events.0
+    }
+
+    #[test]
+    fn generics() {
+        assert_eq!(
+            events("a::B<c::D, (E, [F; 3])>"),
+            [
+                "a",
+                "::",
+                "B",
+                "generics<",
+                "c",
+                "::",
+                "D",
+                ",",
+                "tuple(",
+                "E",
+                ",",
+                "[",
+                "F",
+                ";",
+                "literal 3",
+                "]",
+                ")tuple",
+                ">generics",
+            ]
+        );
+    }
+
+    #[test]
+    fn qualified_paths_and_functions() {
+        assert_eq!(
+            events("<&'a mut T as a::Fn(u8)>::call"),
+            [
+                "qualified<",
+                "'a",
+                "keyword mut",
+                "T",
+                "keyword as",
+                "a",
+                "::",
+                "Fn",
+                "args(",
+                "u8",
+                ")args",
+                ">qualified",
+                "::",
+                "call",
+            ]
+        );
+        assert_eq!(
+            events("fn(a::{{closure}}) -> !"),
+            ["keyword fn", "args(", "a", "::", "{{closure}}", ")args"]
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(events(">)"), [">qualified", ")tuple"]);
+        assert_eq!(events("a::{{closure"), ["a", "::", "{{closure"]);
+    }
+}